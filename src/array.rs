@@ -6,10 +6,11 @@
 pub mod list;
 
 use core::fmt::{Debug, Formatter};
-use std::cmp::Ordering;
-use std::ops::{Index, IndexMut, Range};
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut, Range};
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::prelude::*;
 
 // A trait for collections that can implement an array.
 pub trait ArrayCollection<T>: Collection + Index<usize> + IndexMut<usize>
@@ -96,6 +97,29 @@ impl<T, const N: usize> Debug for Array<T, N>
     }
 }
 
+// Deserialize function for Array
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::Deserialize<'de> for Array<T, N>
+    where
+        T: PartialEq + Clone + Default + Copy + Debug + serde::Deserialize<'de>,
+{
+    /// Deserializes an 'array' from a sequence of elements.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+    {
+        let vec: Vec<T> = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+
+        // Return an error if the sequence length does not match the array length.
+        if vec.len() != N {
+            return Err(serde::de::Error::invalid_length(vec.len(),
+                                                        &"a sequence matching the array length"));
+        }
+
+        Ok(Array::from_vec(&vec))
+    }
+}
+
 // Empty function for Array
 impl<T, const N: usize> Empty for Array<T, N>
     where
@@ -113,6 +137,12 @@ impl<T, const N: usize> Empty for Array<T, N>
     }
 }
 
+// Eq function for Array
+impl<T, const N: usize> Eq for Array<T, N>
+    where
+        T: Eq + Clone + Default + Copy + Debug,
+{}
+
 // Index function for Array
 impl<T, const N: usize> Index<usize> for Array<T, N>
     where
@@ -150,7 +180,7 @@ impl<T, const N: usize> IntoIterator for Array<T, N>
     /// The Item type.
     type Item = T;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     /// Converts this 'array' into an 'iterator'.
     fn into_iter(self) -> Self::IntoIter { self.arr.to_vec().into_iter() }
@@ -167,6 +197,19 @@ impl<T, const N: usize> Len for Array<T, N>
     }
 }
 
+// Ord function for Array
+impl<T, const N: usize> Ord for Array<T, N>
+    where
+        T: Ord + Clone + Default + Copy + Debug,
+{
+    /// Compares this 'array' to the specified 'array' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'array' is less than a longer 'array' it
+    /// is a prefix of.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arr.cmp(&other.arr)
+    }
+}
+
 // PartialEq function for Array
 impl<T, const N: usize> PartialEq for Array<T, N>
     where
@@ -191,6 +234,19 @@ impl<T, const N: usize> PartialEq for Array<T, N>
     }
 }
 
+// PartialOrd function for Array
+impl<T, const N: usize> PartialOrd for Array<T, N>
+    where
+        T: PartialOrd + Clone + Default + Copy + Debug,
+{
+    /// Compares this 'array' to the specified 'array' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'array' is less than a longer 'array' it
+    /// is a prefix of. Returns None if two compared elements are incomparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.arr.partial_cmp(&other.arr)
+    }
+}
+
 // Reversible function for Array
 impl<T, const N: usize> Reversible for Array<T, N>
     where
@@ -208,6 +264,21 @@ impl<T, const N: usize> Reversible for Array<T, N>
     }
 }
 
+// Serialize function for Array
+#[cfg(feature = "serde")]
+impl<T, const N: usize> serde::Serialize for Array<T, N>
+    where
+        T: PartialEq + Clone + Default + Copy + Debug + serde::Serialize,
+{
+    /// Serializes this 'array' as a sequence of elements.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+    {
+        serializer.collect_seq(self.arr.iter())
+    }
+}
+
 // Sortable functions for Array
 impl<T, const N: usize> Sortable for Array<T, N>
     where
@@ -438,4 +509,105 @@ impl<T, const N: usize> Array<T, N>
 
         array
     }
+
+    /// Returns true if every element of this 'array' is within the specified tolerance of the
+    /// element at the same index in the specified 'array'.
+    #[allow(dead_code)]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool
+        where
+            T: Into<f64>,
+    {
+        // If an element is not equal to or within the tolerance of the other element, return false.
+        for i in 0..self.arr.len() {
+            let a: f64 = self.arr[i].into();
+            let b: f64 = other.arr[i].into();
+
+            if !(a == b || (a - b <= epsilon && b - a <= epsilon)) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Binary searches this 'array' for the specified key, comparing it to the key that the
+    /// specified function derives from each element. This 'array' must be sorted by that key.
+    /// Returns Ok with the index of a matching element, or Err with the index where an element
+    /// with the key could be inserted to keep this 'array' sorted.
+    #[allow(dead_code)]
+    pub fn binary_search_by_key<B: Ord, F: Fn(&T) -> B>(&self, key: &B, f: F)
+        -> Result<usize, usize> {
+        self.arr.binary_search_by_key(key, f)
+    }
+
+    /// Splits this 'array' into chunks of the specified size, in order. Returns a vector of the
+    /// full-size chunks along with a vector of the remaining elements that did not fill a chunk.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified chunk size is zero.
+    #[allow(dead_code)]
+    pub fn chunks_exact(&self, size: usize) -> (Vec<Vec<T>>, Vec<T>) {
+        // Panic if the chunk size is zero.
+        if size == 0 {
+            panic!("Cannot split array into chunks due to zero chunk size.");
+        }
+
+        let chunks = self.arr.chunks_exact(size);
+        let rem: Vec<T> = chunks.remainder().to_vec();
+
+        (chunks.map(|c| c.to_vec()).collect(), rem)
+    }
+
+    /// Returns true if the specified slice appears in this 'array' as a contiguous run of elements,
+    /// in the same order. An empty slice is always contained.
+    #[allow(dead_code)]
+    pub fn contains_subslice(&self, needle: &[T]) -> bool {
+        // If the needle is empty, return true.
+        if needle.is_empty() {
+            return true;
+        }
+
+        self.arr.windows(needle.len()).any(|w| w == needle)
+    }
+
+    /// Returns an 'iterator' over the elements of this 'array' in reverse order, from the last
+    /// element to the first. This 'array' is not changed.
+    #[allow(dead_code)]
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.arr.iter().rev()
+    }
+
+    /// Shifts the specified element into the front of this 'array', moving every element one
+    /// index to the right, like a fixed-size shift register. Returns the element shifted out of the
+    /// last index, or the specified element itself if this 'array' has a length of zero.
+    #[allow(dead_code)]
+    pub fn shift_in(&mut self, item: T) -> T {
+        // If this array has no elements, the element is shifted straight out.
+        if N == 0 {
+            return item;
+        }
+
+        let out: T = self.arr[N - 1];
+
+        self.arr.rotate_right(1);
+        self.arr[0] = item;
+
+        out
+    }
+}
+
+// Parallel iterator functions for Array
+#[cfg(feature = "rayon")]
+impl<T, const N: usize> Array<T, N>
+    where
+        T: PartialEq + Clone + Default + Copy + Debug + Sync,
+{
+    /// Returns a parallel 'iterator' over the elements of this 'array'.
+    #[allow(dead_code)]
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        use rayon::prelude::*;
+
+        self.arr.par_iter()
+    }
 }
\ No newline at end of file
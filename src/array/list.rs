@@ -6,12 +6,13 @@
 
 pub mod vector;
 
-use core::fmt::{Debug, Formatter};
-use std::cmp::Ordering;
-use std::ops::{Index, IndexMut, Range};
+use core::fmt::{Debug, Display, Formatter};
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut, Range};
 use len_trait::*;
 use crate::array::*;
 use crate::collection::*;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a 'list'.
 pub trait ListCollection<T>: ArrayCollection<T>
@@ -70,6 +71,7 @@ pub trait ListCollection<T>: ArrayCollection<T>
 // List
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A collection that allows for adding or removing items from a 'list'.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct List<T>
     where
         T: PartialEq + Clone + Debug,
@@ -109,6 +111,38 @@ impl<T> Debug for List<T>
     }
 }
 
+// Default function for List
+impl<T> Default for List<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Returns a new empty 'list'.
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// Display function for List
+impl<T> Display for List<T>
+    where
+        T: PartialEq + Clone + Debug + Display,
+{
+    /// Displays this 'list' in a compact, human-readable form, such as [0, 1, 2].
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+
+        for i in 0..self.arr.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", self.arr[i])?;
+        }
+
+        write!(f, "]")
+    }
+}
+
 // Empty function for List
 impl<T> Empty for List<T>
     where
@@ -118,6 +152,38 @@ impl<T> Empty for List<T>
     fn is_empty(&self) -> bool { self.arr.is_empty() }
 }
 
+// Eq function for List
+impl<T> Eq for List<T>
+    where
+        T: Eq + Clone + Debug,
+{}
+
+// Extend function for List
+impl<T> Extend<T> for List<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Extends this 'list' with the elements of the specified iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.arr.push(i);
+        }
+    }
+}
+
+// FromIterator function for List
+impl<T> FromIterator<T> for List<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Creates a new 'list' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list: List<T> = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
 // Index function for List
 impl<T> Index<usize> for List<T>
     where
@@ -155,7 +221,7 @@ impl<T> IntoIterator for List<T>
     /// The Item type.
     type Item = T;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     /// Converts this 'list' into an 'iterator'.
     fn into_iter(self) -> Self::IntoIter { self.arr.into_iter() }
@@ -172,6 +238,19 @@ impl<T> Len for List<T>
     }
 }
 
+// Ord function for List
+impl<T> Ord for List<T>
+    where
+        T: Ord + Clone + Debug,
+{
+    /// Compares this 'list' to the specified 'list' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'list' is less than a longer 'list' it
+    /// is a prefix of.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arr.cmp(&other.arr)
+    }
+}
+
 // PartialEq function for List
 impl<T> PartialEq for List<T>
     where
@@ -196,6 +275,19 @@ impl<T> PartialEq for List<T>
     }
 }
 
+// PartialOrd function for List
+impl<T> PartialOrd for List<T>
+    where
+        T: PartialOrd + Clone + Debug,
+{
+    /// Compares this 'list' to the specified 'list' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'list' is less than a longer 'list' it
+    /// is a prefix of. Returns None if two compared elements are incomparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.arr.partial_cmp(&other.arr)
+    }
+}
+
 // Reversible function for List
 impl<T> Reversible for List<T>
     where
@@ -583,4 +675,145 @@ impl<T> List<T>
     /// Creates a new 'list' that contains the elements in the specified vector.
     #[allow(dead_code)]
     pub fn from_vec(v: &Vec<T>) -> Self { List { arr: v.clone() } }
+
+    /// Returns true if the specified slice appears in this 'list' as a contiguous run of elements,
+    /// in the same order. An empty slice is always contained.
+    #[allow(dead_code)]
+    pub fn contains_subslice(&self, needle: &[T]) -> bool {
+        // If the needle is empty, return true.
+        if needle.is_empty() {
+            return true;
+        }
+
+        self.arr.windows(needle.len()).any(|w| w == needle)
+    }
+
+    /// Returns the index of the first element in this 'list' that satisfies the specified
+    /// predicate along with a reference to the element, or None if no element satisfies it.
+    #[allow(dead_code)]
+    pub fn find<F: Fn(&T) -> bool>(&self, pred: F) -> Option<(usize, &T)> {
+        // If an element satisfies the predicate, return its index and the element.
+        for i in 0..self.arr.len() {
+            if pred(&self.arr[i]) {
+                return Some((i, &self.arr[i]));
+            }
+        }
+
+        None
+    }
+
+    /// Folds the elements of this 'list' into a single value, starting with the specified initial
+    /// value and applying the specified function to the accumulated value and each element in
+    /// order. Returns the final accumulated value. This 'list' is not changed.
+    #[allow(dead_code)]
+    pub fn fold<B, F: Fn(B, &T) -> B>(&self, init: B, f: F) -> B {
+        let mut acc: B = init;
+
+        for i in 0..self.arr.len() {
+            acc = f(acc, &self.arr[i]);
+        }
+
+        acc
+    }
+
+    /// Returns a new 'list' containing the unique elements that are in both this 'list' and the
+    /// specified 'list', in the order they first appear in this 'list'.
+    #[allow(dead_code)]
+    pub fn intersection(&self, other: &List<T>) -> List<T> {
+        let mut list: List<T> = List::new();
+
+        // Add each element of this list that is in the other list and not already added.
+        for i in 0..self.arr.len() {
+            if other.arr.contains(&self.arr[i]) && !list.arr.contains(&self.arr[i]) {
+                list.arr.push(self.arr[i].clone());
+            }
+        }
+
+        list
+    }
+
+    /// Inserts the specified element into this 'list' at the position that keeps it sorted in
+    /// ascending order, assuming this 'list' is already sorted. The element is inserted after
+    /// any elements equal to it. Returns the index the element was inserted at.
+    #[allow(dead_code)]
+    pub fn insert_sorted(&mut self, item: T) -> usize
+        where
+            T: PartialOrd,
+    {
+        let mut low: usize = 0;
+        let mut high: usize = self.arr.len();
+
+        // Binary search for the first element greater than item.
+        while low < high {
+            let mid: usize = low + (high - low) / 2;
+
+            if self.arr[mid] <= item {
+                low = mid + 1;
+            }
+            else {
+                high = mid;
+            }
+        }
+
+        self.arr.insert(low, item);
+
+        low
+    }
+
+    /// Returns an 'iterator' over the elements of this 'list' in reverse order, from the last
+    /// element to the first. This 'list' is not changed.
+    #[allow(dead_code)]
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.arr.iter().rev()
+    }
+
+    /// Returns the index of the last element in this 'list' that satisfies the specified
+    /// predicate along with a reference to the element, or None if no element satisfies it.
+    #[allow(dead_code)]
+    pub fn rfind<F: Fn(&T) -> bool>(&self, pred: F) -> Option<(usize, &T)> {
+        // Starting from the end of the list, if an element satisfies the predicate, return its
+        // index and the element.
+        for i in (0..self.arr.len()).rev() {
+            if pred(&self.arr[i]) {
+                return Some((i, &self.arr[i]));
+            }
+        }
+
+        None
+    }
+
+    /// Removes the element at the specified index and returns it, replacing it with the last
+    /// element of this 'list'. This does not preserve the order of the elements, but does not
+    /// shift the elements after the index like removing from the middle of this 'list' does.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified index is out-of-bounds.
+    #[allow(dead_code)]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        // Panic if the index is out-of-bounds.
+        if index >= self.arr.len() {
+            panic!("Cannot remove the list element due to out-of-bounds index.");
+        }
+
+        self.arr.swap_remove(index)
+    }
+
+    /// Returns a new 'list' containing the unique elements that are in this 'list' or the
+    /// specified 'list'. Elements keep the order they first appear in this 'list', followed by
+    /// the new elements in the order they first appear in the specified 'list'.
+    #[allow(dead_code)]
+    pub fn union(&self, other: &List<T>) -> List<T> {
+        let mut list: List<T> = List::new();
+
+        // Add each element of this list, then each element of the other list, that has not
+        // already been added.
+        for i in self.arr.iter().chain(other.arr.iter()) {
+            if !list.arr.contains(i) {
+                list.arr.push(i.clone());
+            }
+        }
+
+        list
+    }
 }
\ No newline at end of file
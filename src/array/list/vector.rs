@@ -4,13 +4,14 @@
 //! implementation of a vector called 'Vector'. A 'vector' is a resizable list of elements that
 //! can add, insert, or remove any elements.
 
-use core::fmt::{Debug, Formatter};
-use std::cmp::Ordering;
-use std::ops::{Index, IndexMut, Range};
+use core::fmt::{Debug, Display, Formatter};
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut, Range};
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
 use crate::array::*;
 use crate::array::list::*;
+use crate::prelude::*;
 
 // A trait for collections that can implement a vector.
 pub trait VectorCollection<T>: ListCollection<T>
@@ -35,6 +36,20 @@ pub trait VectorCollection<T>: ListCollection<T>
     /// Sets the capacity to match the current length of this 'vector'.
     fn shrink(&mut self);
 
+    /// Shrinks the capacity of this 'vector' down to the specified minimum capacity. The capacity
+    /// is never reduced below the length of this 'vector', and is not changed if it is already
+    /// less than the specified minimum capacity.
+    fn shrink_to(&mut self, min_capacity: usize);
+
+    /// Removes the elements within the specified index 'range' and inserts the elements of the
+    /// specified vector in their place. An empty 'range' only inserts the elements, and an
+    /// empty vector only removes them.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified 'range' is out-of-bounds.
+    fn splice(&mut self, r: Range<usize>, replacement: Vec<T>);
+
     /// Truncates the length of this 'vector' to the specified length.
     fn truncate(&mut self, less: usize);
 }
@@ -43,6 +58,7 @@ pub trait VectorCollection<T>: ListCollection<T>
 // Vector
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A resizable collection of elements that can be randomly accessed and altered.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector<T>
     where
         T: PartialEq + Clone + Debug,
@@ -86,6 +102,38 @@ impl<T> Debug for Vector<T>
     }
 }
 
+// Default function for Vector
+impl<T> Default for Vector<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Returns a new empty 'vector'.
+    fn default() -> Self {
+        Vector::new()
+    }
+}
+
+// Display function for Vector
+impl<T> Display for Vector<T>
+    where
+        T: PartialEq + Clone + Debug + Display,
+{
+    /// Displays this 'vector' in a compact, human-readable form, such as [0, 1, 2].
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "[")?;
+
+        for i in 0..self.arr.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", self.arr[i])?;
+        }
+
+        write!(f, "]")
+    }
+}
+
 // Empty function for Vector
 impl<T> Empty for Vector<T>
     where
@@ -97,6 +145,40 @@ impl<T> Empty for Vector<T>
     }
 }
 
+// Eq function for Vector
+impl<T> Eq for Vector<T>
+    where
+        T: Eq + Clone + Debug,
+{}
+
+// Extend function for Vector
+impl<T> Extend<T> for Vector<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Extends this 'vector' with the elements of the specified iterator. Since
+    /// 'VectorCollection' also has an extend function, calls may need to be written as
+    /// Extend::extend(&mut vector, iter) when both traits are in scope.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.arr.push(i);
+        }
+    }
+}
+
+// FromIterator function for Vector
+impl<T> FromIterator<T> for Vector<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Creates a new 'vector' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector: Vector<T> = Vector::new();
+        Extend::extend(&mut vector, iter);
+        vector
+    }
+}
+
 // Index function for Vector
 impl<T> Index<usize> for Vector<T>
     where
@@ -134,7 +216,7 @@ impl<T> IntoIterator for Vector<T>
     /// The Item type.
     type Item = T;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     /// Converts this 'vector' into an 'iterator'.
     fn into_iter(self) -> Self::IntoIter {
@@ -153,6 +235,19 @@ impl<T> Len for Vector<T>
     }
 }
 
+// Ord function for Vector
+impl<T> Ord for Vector<T>
+    where
+        T: Ord + Clone + Debug,
+{
+    /// Compares this 'vector' to the specified 'vector' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'vector' is less than a longer
+    /// 'vector' it is a prefix of.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.arr.cmp(&other.arr)
+    }
+}
+
 // PartialEq function for Vector
 impl<T> PartialEq for Vector<T>
     where
@@ -177,6 +272,19 @@ impl<T> PartialEq for Vector<T>
     }
 }
 
+// PartialOrd function for Vector
+impl<T> PartialOrd for Vector<T>
+    where
+        T: PartialOrd + Clone + Debug,
+{
+    /// Compares this 'vector' to the specified 'vector' lexicographically, meaning elements are
+    /// compared in order until one differs, and a shorter 'vector' is less than a longer
+    /// 'vector' it is a prefix of. Returns None if two compared elements are incomparable.
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.arr.partial_cmp(&other.arr)
+    }
+}
+
 // Reversible function for Vector
 impl<T> Reversible for Vector<T>
     where
@@ -223,7 +331,9 @@ impl<T> Sortable for Vector<T>
         true
     }
 
-    /// Sorts the elements in this 'vector' in ascending order.
+    /// Sorts the elements in this 'vector' in ascending order. This sort is stable, meaning
+    /// elements that compare equal keep their original order. Use 'sort_unstable' when that order
+    /// does not matter.
     fn sort(&mut self) {
         // Convert list into a vector.
         let mut vec: Vec<T> = self.to_vec();
@@ -561,6 +671,24 @@ impl<T> VectorCollection<T> for Vector<T>
         self.arr.shrink_to_fit();
     }
 
+    /// Shrinks the capacity of this 'vector' down to the specified minimum capacity. The capacity
+    /// is never reduced below the length of this 'vector', and is not changed if it is already
+    /// less than the specified minimum capacity.
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.arr.shrink_to(min_capacity);
+    }
+
+    /// Removes the elements within the specified index 'range' and inserts the elements of the
+    /// specified vector in their place. An empty 'range' only inserts the elements, and an
+    /// empty vector only removes them.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified 'range' is out-of-bounds.
+    fn splice(&mut self, r: Range<usize>, replacement: Vec<T>) {
+        self.arr.splice(r, replacement); // Panics if 'r' is out-of-bounds.
+    }
+
     /// Truncates the length of this 'vector' to the specified length.
     fn truncate(&mut self, length: usize) {
         self.arr.truncate(length);
@@ -598,6 +726,180 @@ impl<T> Vector<T>
         Vector { arr: v.clone() }
     }
 
+    /// Returns true if every element of this 'vector' is within the specified tolerance of the
+    /// element at the same index in the specified 'vector'. 'Vectors' of different lengths are
+    /// never approximately equal.
+    #[allow(dead_code)]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool
+        where
+            T: Into<f64> + Copy,
+    {
+        // If lengths do not match, return false.
+        if self.arr.len() != other.arr.len() {
+            return false;
+        }
+
+        // If an element is not equal to or within the tolerance of the other element, return false.
+        for i in 0..self.arr.len() {
+            let a: f64 = self.arr[i].into();
+            let b: f64 = other.arr[i].into();
+
+            if !(a == b || (a - b <= epsilon && b - a <= epsilon)) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Binary searches this 'vector' for the specified key, comparing it to the key that the
+    /// specified function derives from each element. This 'vector' must be sorted by that key.
+    /// Returns Ok with the index of a matching element, or Err with the index where an element
+    /// with the key could be inserted to keep this 'vector' sorted.
+    #[allow(dead_code)]
+    pub fn binary_search_by_key<B: Ord, F: Fn(&T) -> B>(&self, key: &B, f: F)
+        -> Result<usize, usize> {
+        self.arr.binary_search_by_key(key, f)
+    }
+
+    /// Splits this 'vector' into chunks of the specified size, in order. Returns a vector of the
+    /// full-size chunks along with a vector of the remaining elements that did not fill a chunk.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified chunk size is zero.
+    #[allow(dead_code)]
+    pub fn chunks_exact(&self, size: usize) -> (Vec<Vec<T>>, Vec<T>) {
+        // Panic if the chunk size is zero.
+        if size == 0 {
+            panic!("Cannot split vector into chunks due to zero chunk size.");
+        }
+
+        let chunks = self.arr.chunks_exact(size);
+        let rem: Vec<T> = chunks.remainder().to_vec();
+
+        (chunks.map(|c| c.to_vec()).collect(), rem)
+    }
+
+    /// Returns true if the specified slice appears in this 'vector' as a contiguous run of
+    /// elements, in the same order. An empty slice is always contained.
+    #[allow(dead_code)]
+    pub fn contains_subslice(&self, needle: &[T]) -> bool {
+        // If the needle is empty, return true.
+        if needle.is_empty() {
+            return true;
+        }
+
+        self.arr.windows(needle.len()).any(|w| w == needle)
+    }
+
+    /// Returns the index of the first element in this 'vector' that satisfies the specified
+    /// predicate along with a reference to the element, or None if no element satisfies it.
+    #[allow(dead_code)]
+    pub fn find<F: Fn(&T) -> bool>(&self, pred: F) -> Option<(usize, &T)> {
+        // If an element satisfies the predicate, return its index and the element.
+        for i in 0..self.arr.len() {
+            if pred(&self.arr[i]) {
+                return Some((i, &self.arr[i]));
+            }
+        }
+
+        None
+    }
+
+    /// Folds the elements of this 'vector' into a single value, starting with the specified initial
+    /// value and applying the specified function to the accumulated value and each element in
+    /// order. Returns the final accumulated value. This 'vector' is not changed.
+    #[allow(dead_code)]
+    pub fn fold<B, F: Fn(B, &T) -> B>(&self, init: B, f: F) -> B {
+        let mut acc: B = init;
+
+        for i in 0..self.arr.len() {
+            acc = f(acc, &self.arr[i]);
+        }
+
+        acc
+    }
+
+    /// Inserts the specified element into this 'vector' at the position that keeps it sorted in
+    /// ascending order, assuming this 'vector' is already sorted. The element is inserted after
+    /// any elements equal to it. Returns the index the element was inserted at.
+    #[allow(dead_code)]
+    pub fn insert_sorted(&mut self, item: T) -> usize
+        where
+            T: PartialOrd,
+    {
+        let mut low: usize = 0;
+        let mut high: usize = self.arr.len();
+
+        // Binary search for the first element greater than item.
+        while low < high {
+            let mid: usize = low + (high - low) / 2;
+
+            if self.arr[mid] <= item {
+                low = mid + 1;
+            }
+            else {
+                high = mid;
+            }
+        }
+
+        self.arr.insert(low, item);
+
+        low
+    }
+
+    /// Returns an 'iterator' over the elements of this 'vector' in reverse order, from the last
+    /// element to the first. This 'vector' is not changed.
+    #[allow(dead_code)]
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.arr.iter().rev()
+    }
+
+    /// Returns the index of the last element in this 'vector' that satisfies the specified
+    /// predicate along with a reference to the element, or None if no element satisfies it.
+    #[allow(dead_code)]
+    pub fn rfind<F: Fn(&T) -> bool>(&self, pred: F) -> Option<(usize, &T)> {
+        // Starting from the end of the vector, if an element satisfies the predicate, return its
+        // index and the element.
+        for i in (0..self.arr.len()).rev() {
+            if pred(&self.arr[i]) {
+                return Some((i, &self.arr[i]));
+            }
+        }
+
+        None
+    }
+
+    /// Sorts the elements in this 'vector' in ascending order without allocating. This sort is
+    /// unstable, meaning elements that compare equal may not keep their original order, but it is
+    /// typically faster than 'sort'. Incomparable elements are considered less than all other
+    /// elements.
+    #[allow(dead_code)]
+    pub fn sort_unstable(&mut self)
+        where
+            T: PartialOrd,
+    {
+        self.arr.sort_unstable_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Less));
+    }
+
+    /// Removes the element at the specified index and returns it, replacing it with the last
+    /// element of this 'vector'. This does not preserve the order of the elements, but does not
+    /// shift the elements after the index like removing from the middle of this 'vector' does.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the specified index is out-of-bounds.
+    #[allow(dead_code)]
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        // Panic if the index is out-of-bounds.
+        if index >= self.arr.len() {
+            panic!("Cannot remove the vector element due to out-of-bounds index.");
+        }
+
+        self.arr.swap_remove(index)
+    }
+
     /// Creates a new 'vector' with the specified capacity.
     #[allow(dead_code)]
     pub fn with_capacity(capacity: usize) -> Self {
@@ -617,4 +919,19 @@ impl<T> Vector<T>
 
         new
     }
+}
+
+// Parallel iterator functions for Vector
+#[cfg(feature = "rayon")]
+impl<T> Vector<T>
+    where
+        T: PartialEq + Clone + Debug + Sync,
+{
+    /// Returns a parallel 'iterator' over the elements of this 'vector'.
+    #[allow(dead_code)]
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        use rayon::prelude::*;
+
+        self.arr.par_iter()
+    }
 }
\ No newline at end of file
@@ -5,6 +5,7 @@
 
 use core::fmt::Debug;
 use len_trait::len::*;
+use crate::prelude::*;
 
 // A trait for any type of collection.
 pub trait Collection:
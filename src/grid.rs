@@ -5,13 +5,18 @@
 //! 'AdjacencyMatrix'. A 'grid' is a list of elements arranged in an NxM resizable grid.
 
 use core::fmt::{Debug, Display, Formatter};
-use std::cmp::Ordering;
-use std::ops::{Index, IndexMut};
+use core::cmp::Ordering;
+use core::ops::{Index, IndexMut};
+#[cfg(feature = "std")]
 use chrono::{DateTime, Local, Utc};
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::map::traversable::linked::{LinkedCollection, LinkedList};
+use crate::supers::{AdjacencyList, SuperCollection};
+use crate::prelude::*;
 
 /// Contains data for a row/column grid 'position'.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub struct Pos {
     row: usize,
@@ -45,12 +50,15 @@ impl Pos {
         Pos { row, col }
     }
 
-    /// Returns the distance from this 'position' and another 'position'.
+    /// Returns the distance from this 'position' and another 'position'. Requires the 'std'
+    /// feature.
+    #[cfg(feature = "std")]
     #[allow(dead_code)]
     pub fn dist_from(&self, other: Pos) -> f64 {
-        let ret: f64 = ((other.row - self.row) * (other.row - self.row) +
-            (other.col - self.col) * (other.col - self.col)) as f64;
-        ret.sqrt()
+        // Cast to f64 before subtracting so the difference cannot underflow.
+        let rows: f64 = other.row as f64 - self.row as f64;
+        let cols: f64 = other.col as f64 - self.col as f64;
+        (rows * rows + cols * cols).sqrt()
     }
 
     /// Creates a new 'position' initialized at 0, 0.
@@ -67,6 +75,33 @@ impl Pos {
     }
 }
 
+/// Contains the errors that can occur when creating a 'grid' from its parts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GridError {
+    /// The number of elements does not match the number of rows times the number of columns.
+    LengthMismatch {
+        /// The number of elements the 'grid' needs.
+        expected: usize,
+        /// The number of elements that were given.
+        actual: usize,
+    },
+}
+
+// Display function for GridError
+impl Display for GridError {
+    /// Displays a description of this 'grid error'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GridError::LengthMismatch { expected, actual } =>
+                write!(f, "expected {} elements but found {}", expected, actual),
+        }
+    }
+}
+
+// Error function for GridError
+#[cfg(feature = "std")]
+impl std::error::Error for GridError {}
+
 // A trait for collections that can implement a grid.
 pub trait GridCollection<T>: Collection + Index<(usize, usize)> + IndexMut<(usize, usize)>
     where
@@ -173,6 +208,8 @@ pub trait GridCollection<T>: Collection + Index<(usize, usize)> + IndexMut<(usiz
 // Grid
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A resizable collection of NxM elements that can be randomly accessed and altered.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "GridParts<T>"))]
 pub struct Grid<T>
     where
         T: PartialEq + PartialOrd + Clone + Default + Debug,
@@ -185,6 +222,35 @@ pub struct Grid<T>
     rows: usize,
 }
 
+/// Contains the deserialized fields of a 'grid' before the number of elements is checked
+/// against the number of rows and columns.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct GridParts<T> {
+    /// The array of elements backing the 'grid'.
+    arr: Vec<T>,
+    /// The number of columns in the 'grid'.
+    cols: usize,
+    /// The number of rows in the 'grid'.
+    rows: usize,
+}
+
+// TryFrom function for Grid
+#[cfg(feature = "serde")]
+impl<T> TryFrom<GridParts<T>> for Grid<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Default + Debug,
+{
+    /// Error type.
+    type Error = GridError;
+
+    /// Creates a 'grid' from its deserialized fields. Returns a 'grid error' if the number of
+    /// elements is not the number of rows times the number of columns.
+    fn try_from(parts: GridParts<T>) -> Result<Self, Self::Error> {
+        Grid::from_parts(parts.arr, parts.rows, parts.cols)
+    }
+}
+
 // Clear function for Grid
 impl<T> Clear for Grid<T>
     where
@@ -228,6 +294,55 @@ impl<T> Debug for Grid<T>
     }
 }
 
+// Default function for Grid
+impl<T> Default for Grid<T>
+    where
+        T: Clone + Debug + Default + PartialEq + PartialOrd,
+{
+    /// Returns a new empty 'grid'.
+    fn default() -> Self {
+        Grid::new()
+    }
+}
+
+// Display function for Grid
+impl<T> Display for Grid<T>
+    where
+        T: Clone + Debug + Default + PartialEq + PartialOrd + Display,
+{
+    /// Displays this 'grid' as a matrix with one row per line, where every element is right
+    /// aligned to the width of the widest element.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        // Find the width of the widest element.
+        let mut width: usize = 0;
+
+        for i in 0..self.arr.len() {
+            width = width.max(self.arr[i].to_string().len());
+        }
+
+        // Write each row on its own line.
+        for i in 0..self.rows {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            write!(f, "[")?;
+
+            for j in 0..self.cols {
+                if j > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "{:>width$}", self.arr[j + (i * self.cols)].to_string(), width = width)?;
+            }
+
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
 // Empty function for Grid
 impl<T> Empty for Grid<T>
     where
@@ -449,9 +564,10 @@ impl<T> GridCollection<T> for Grid<T>
             panic!("Cannot insert column into grid due to out-of-bounds column index.");
         }
 
-        // Panic if the number of values does not match the row count.
-        if val.len() > self.rows {
-            panic!("Cannot insert column into grid due to invalid vector length.");
+        // Panic if the number of values does not match the row count. If there are
+        // no rows, a single value is expected since a row is added below.
+        if val.len() != self.rows.max(1) {
+            panic!("Cannot insert column into grid due to vector length not matching row count.");
         }
 
         // If there are no rows, add a row.
@@ -507,9 +623,10 @@ impl<T> GridCollection<T> for Grid<T>
             panic!("Cannot insert row into grid due to out-of-bounds row index.");
         }
 
-        // Panic if the number of values does not match the column count.
-        if val.len() > self.cols {
-            panic!("Cannot insert row into grid due to invalid vector length.");
+        // Panic if the number of values does not match the column count. If there are
+        // no columns, a single value is expected since a column is added below.
+        if val.len() != self.cols.max(1) {
+            panic!("Cannot insert row into grid due to vector length not matching column count.");
         }
 
         // If there are no columns, add a column.
@@ -741,6 +858,294 @@ impl<T> Grid<T>
 
         grid
     }
+
+    /// Creates a new 'grid' with the specified number of rows and columns that takes ownership of
+    /// the specified vector as its elements, in row-major order, without copying them. Returns a
+    /// 'grid error' if the length of the vector is not the number of rows times the number of
+    /// columns.
+    #[allow(dead_code)]
+    pub fn from_parts(arr: Vec<T>, rows: usize, cols: usize) -> Result<Grid<T>, GridError> {
+        // If the vector length does not match the grid size, return an error.
+        if arr.len() != rows * cols {
+            return Err(GridError::LengthMismatch { expected: rows * cols, actual: arr.len() });
+        }
+
+        Ok(Grid { arr, cols, rows })
+    }
+
+    /// Resizes this 'grid' to have the specified number of rows and columns, keeping each element
+    /// that still fits at the same row and column. Elements outside of the new size are removed
+    /// and new elements are set to their default value.
+    #[allow(dead_code)]
+    pub fn resize_preserve(&mut self, rows: usize, cols: usize) {
+        let mut arr: Vec<T> = Vec::with_capacity(rows * cols);
+
+        // Copy overlapping elements by their old position and add default values for new elements.
+        for i in 0..rows {
+            for j in 0..cols {
+                if i < self.rows && j < self.cols {
+                    arr.push(self.arr[j + (i * self.cols)].clone());
+                }
+                else {
+                    arr.push(T::default());
+                }
+            }
+        }
+
+        // Update elements and row and column count.
+        self.arr = arr;
+        self.rows = rows;
+        self.cols = cols;
+    }
+
+    /// Returns a mutable reference to the element at the specified 'position' or None if the
+    /// position is out-of-bounds.
+    #[allow(dead_code)]
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if pos.row >= self.rows || pos.col >= self.cols {
+            return None;
+        }
+
+        Some(&mut self.arr[pos.col + (pos.row * self.cols)])
+    }
+
+    /// Consumes this 'grid' and returns its elements in row-major order, without copying them,
+    /// along with its number of rows and number of columns. Use 'from_parts' to rebuild the
+    /// 'grid'.
+    #[allow(dead_code)]
+    pub fn into_parts(self) -> (Vec<T>, usize, usize) {
+        (self.arr, self.rows, self.cols)
+    }
+
+    /// Returns a vector containing a copy of each column in this 'grid', in column order.
+    #[allow(dead_code)]
+    pub fn iter_cols(&self) -> Vec<Vec<T>> {
+        let mut cols: Vec<Vec<T>> = Vec::with_capacity(self.cols);
+
+        for _ in 0..self.cols {
+            cols.push(Vec::with_capacity(self.rows));
+        }
+
+        // Add each element to the vector of its column.
+        for i in 0..self.arr.len() {
+            cols[i % self.cols].push(self.arr[i].clone());
+        }
+
+        cols
+    }
+
+    /// Returns a vector containing a copy of each row in this 'grid', in row order.
+    #[allow(dead_code)]
+    pub fn iter_rows(&self) -> Vec<Vec<T>> {
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(self.rows);
+
+        // Add each row of elements as a vector.
+        for i in 0..self.rows {
+            rows.push(self.arr[(i * self.cols)..((i + 1) * self.cols)].to_vec());
+        }
+
+        rows
+    }
+
+    /// Returns a vector containing a copy of every diagonal in this 'grid'. The diagonals running
+    /// down and to the right come first, from the bottom-left corner to the top-right corner,
+    /// followed by the diagonals running down and to the left, from the top-left corner to the
+    /// bottom-right corner. The elements of each diagonal are ordered from top to bottom. Unlike
+    /// 'main_diagonal' and 'anti_diagonal', this works for a 'grid' of any size.
+    #[allow(dead_code)]
+    pub fn all_diagonals(&self) -> Vec<Vec<T>> {
+        let mut diags: Vec<Vec<T>> = Vec::new();
+
+        // If the grid is empty, return no diagonals.
+        if self.rows == 0 || self.cols == 0 {
+            return diags;
+        }
+
+        let count: usize = self.rows + self.cols - 1;
+
+        // Add each diagonal running down and to the right, starting from the first column and
+        // then the first row.
+        for k in 0..count {
+            let mut diag: Vec<T> = Vec::new();
+            let mut i: usize = if k < self.rows { self.rows - 1 - k } else { 0 };
+            let mut j: usize = if k < self.rows { 0 } else { k + 1 - self.rows };
+
+            while i < self.rows && j < self.cols {
+                diag.push(self.arr[j + (i * self.cols)].clone());
+                i += 1;
+                j += 1;
+            }
+
+            diags.push(diag);
+        }
+
+        // Add each diagonal running down and to the left, where the row plus column equals k.
+        for k in 0..count {
+            let mut diag: Vec<T> = Vec::new();
+            let start: usize = if k < self.cols { 0 } else { k + 1 - self.cols };
+
+            for i in start..self.rows.min(k + 1) {
+                diag.push(self.arr[(k - i) + (i * self.cols)].clone());
+            }
+
+            diags.push(diag);
+        }
+
+        diags
+    }
+
+    /// Returns a vector containing a copy of the elements on the anti-diagonal of this 'grid',
+    /// meaning the elements where the row plus the column equals the number of columns minus one,
+    /// ordered from the top-right corner to the bottom-left corner. The anti-diagonal is only
+    /// defined for a square 'grid', so an empty vector is returned if this 'grid' is not square.
+    #[allow(dead_code)]
+    pub fn anti_diagonal(&self) -> Vec<T> {
+        let mut diag: Vec<T> = Vec::new();
+
+        // If the grid is not square, return an empty vector.
+        if self.rows != self.cols {
+            return diag;
+        }
+
+        for i in 0..self.rows {
+            diag.push(self.arr[(self.cols - 1 - i) + (i * self.cols)].clone());
+        }
+
+        diag
+    }
+
+    /// Returns true if every element of this 'grid' is within the specified tolerance of the
+    /// element at the same 'position' in the specified 'grid'. 'Grids' with different numbers of
+    /// rows or columns are never approximately equal.
+    #[allow(dead_code)]
+    pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool
+        where
+            T: Into<f64> + Copy,
+    {
+        // If rows or columns do not match, return false.
+        if self.rows != other.rows || self.cols != other.cols {
+            return false;
+        }
+
+        // If an element is not equal to or within the tolerance of the other element, return false.
+        for i in 0..self.arr.len() {
+            let a: f64 = self.arr[i].into();
+            let b: f64 = other.arr[i].into();
+
+            if !(a == b || (a - b <= epsilon && b - a <= epsilon)) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns a new 'grid' of the same size where each element is computed by the specified
+    /// function from the element at the same 'position' in this 'grid' and its in-bounds
+    /// neighbors. Neighbors are the elements above, below, left and right of the element, plus the
+    /// four diagonal elements if diagonal is true, and are passed to the function in row-major
+    /// order. This 'grid' is not changed, so every element is computed from the original values.
+    #[allow(dead_code)]
+    pub fn convolve<F: Fn(&T, &[&T]) -> T>(&self, f: F, diagonal: bool) -> Grid<T> {
+        let mut arr: Vec<T> = Vec::with_capacity(self.arr.len());
+
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                let mut neighbors: Vec<&T> = Vec::new();
+
+                // Add each in-bounds element in the 3x3 window around the current position,
+                // skipping the current position and, if diagonal is false, the corners.
+                for r in i.saturating_sub(1)..(i + 2).min(self.rows) {
+                    for c in j.saturating_sub(1)..(j + 2).min(self.cols) {
+                        if (r == i && c == j) || (!diagonal && r != i && c != j) {
+                            continue;
+                        }
+
+                        neighbors.push(&self.arr[c + (r * self.cols)]);
+                    }
+                }
+
+                arr.push(f(&self.arr[j + (i * self.cols)], &neighbors));
+            }
+        }
+
+        Grid { arr, cols: self.cols, rows: self.rows }
+    }
+
+    /// Returns the number of elements in this 'grid' that are equal to the specified element.
+    #[allow(dead_code)]
+    pub fn count(&self, item: &T) -> usize {
+        self.count_where(|x| x == item)
+    }
+
+    /// Returns the number of elements in this 'grid' that satisfy the specified predicate.
+    #[allow(dead_code)]
+    pub fn count_where<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        let mut count: usize = 0;
+
+        // If the element satisfies the predicate, increase the count.
+        for i in 0..self.arr.len() {
+            if pred(&self.arr[i]) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Returns a vector containing every 'position' in this 'grid' whose element satisfies the
+    /// specified predicate, in row-major order. Returns an empty vector if no element satisfies
+    /// the predicate.
+    #[allow(dead_code)]
+    pub fn find_positions<F: Fn(&T) -> bool>(&self, pred: F) -> Vec<Pos> {
+        let mut list: Vec<Pos> = Vec::new();
+
+        // If the value at a position satisfies the predicate, add position to the list.
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                if pred(&self.arr[j + (i * self.cols)]) {
+                    list.push(Pos::at(i, j));
+                }
+            }
+        }
+
+        list
+    }
+
+    /// Returns a vector containing a copy of the elements on the main diagonal of this 'grid',
+    /// meaning the elements where the row equals the column, ordered from the top-left corner to
+    /// the bottom-right corner. The main diagonal is only defined for a square 'grid', so an empty
+    /// vector is returned if this 'grid' is not square.
+    #[allow(dead_code)]
+    pub fn main_diagonal(&self) -> Vec<T> {
+        let mut diag: Vec<T> = Vec::new();
+
+        // If the grid is not square, return an empty vector.
+        if self.rows != self.cols {
+            return diag;
+        }
+
+        for i in 0..self.rows {
+            diag.push(self.arr[i + (i * self.cols)].clone());
+        }
+
+        diag
+    }
+}
+
+// Parallel iterator functions for Grid
+#[cfg(feature = "rayon")]
+impl<T> Grid<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Default + Debug + Sync,
+{
+    /// Returns a parallel 'iterator' over the elements of this 'grid'.
+    #[allow(dead_code)]
+    pub fn par_iter(&self) -> rayon::slice::Iter<'_, T> {
+        use rayon::prelude::*;
+
+        self.arr.par_iter()
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -760,12 +1165,14 @@ pub enum CellType {
     /// 64-bit signed integer 'cell' data type.
     #[allow(dead_code)]
     Integer(i64),
-    /// Local date/time 'cell' data type.
+    /// Local date/time 'cell' data type. Requires the 'std' feature.
+    #[cfg(feature = "std")]
     #[allow(dead_code)]
     LocalDateTime(DateTime<Local>),
     /// String 'cell' data type.
     String(String),
-    /// UTC date/time 'cell' data type.
+    /// UTC date/time 'cell' data type. Requires the 'std' feature.
+    #[cfg(feature = "std")]
     #[allow(dead_code)]
     UTCDateTime(DateTime<Utc>),
 }
@@ -806,8 +1213,10 @@ impl Display for Cell {
             CellType::Empty => {},
             CellType::Float(f) => datastr = f.to_string(),
             CellType::Integer(n) => datastr = n.to_string(),
+            #[cfg(feature = "std")]
             CellType::LocalDateTime(d) => datastr = d.to_string(),
             CellType::String(s) => datastr = s.clone(),
+            #[cfg(feature = "std")]
             CellType::UTCDateTime(d) => datastr = d.to_string(),
         }
 
@@ -1232,9 +1641,10 @@ impl GridCollection<Cell> for Table {
             panic!("Cannot insert column into table due to out-of-bounds column index.");
         }
 
-        // Panic if the number of values does not match the row count.
-        if val.len() > self.rows {
-            panic!("Cannot insert column into table due to invalid vector length.");
+        // Panic if the number of values does not match the row count. If there are
+        // no rows, a single value is expected since a row is added below.
+        if val.len() != self.rows.max(1) {
+            panic!("Cannot insert column into table due to vector length not matching row count.");
         }
 
         // If there are no rows, add a row.
@@ -1318,9 +1728,10 @@ impl GridCollection<Cell> for Table {
             panic!("Cannot insert row into table due to out-of-bounds row index.");
         }
 
-        // Panic if the number of values does not match the column count.
-        if val.len() > self.cols {
-            panic!("Cannot insert row into table due to invalid vector length.");
+        // Panic if the number of values does not match the column count. If there are
+        // no columns, a single value is expected since a column is added below.
+        if val.len() != self.cols.max(1) {
+            panic!("Cannot insert row into table due to vector length not matching column count.");
         }
 
         // If there are no columns, add a column.
@@ -1600,6 +2011,46 @@ impl Table {
         table
     }
 
+    /// Keeps only the rows of this 'table' whose 'cell' in the specified column satisfies the
+    /// specified predicate. All other rows are removed along with their row headers.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the column index is out-of-bounds.
+    #[allow(dead_code)]
+    pub fn filter_rows<F: Fn(&Cell) -> bool>(&mut self, col: usize, pred: F) {
+        // Panic if column index is out-of-bounds.
+        if col >= self.cols {
+            panic!("Cannot filter table rows due to out-of-bounds column index.");
+        }
+
+        let mut arr: Vec<Cell> = Vec::new();
+        let mut header: Vec<Cell> = Vec::new();
+        let mut rows: usize = 0;
+
+        // Copy each row, and its row header, whose cell in the column satisfies the predicate.
+        for i in 0..self.rows {
+            if pred(&self.arr[col + (i * self.cols)]) {
+                arr.extend_from_slice(&self.arr[(i * self.cols)..((i + 1) * self.cols)]);
+
+                if let Some(vec) = &self.row_header {
+                    header.push(vec[i].clone());
+                }
+
+                rows += 1;
+            }
+        }
+
+        // Update cells, row headers and row count.
+        self.arr = arr;
+
+        if self.row_header.is_some() {
+            self.row_header = Some(header);
+        }
+
+        self.rows = rows;
+    }
+
     /// Removes column headers from this 'table'.
     #[allow(dead_code)]
     pub fn no_col_headers(&mut self) {
@@ -1619,6 +2070,24 @@ impl Table {
         self.row_header = None;
     }
 
+    /// Applies the specified function to every 'cell' in this 'table', and replaces the 'cell'
+    /// with the returned 'cell' whenever the function returns one. Headers are not changed.
+    /// Returns the number of 'cells' that were replaced.
+    #[allow(dead_code)]
+    pub fn replace_cells<F: Fn(&Cell) -> Option<Cell>>(&mut self, f: F) -> usize {
+        let mut count: usize = 0;
+
+        // If the function returns a new cell, replace the current cell with it.
+        for i in 0..self.arr.len() {
+            if let Some(cell) = f(&self.arr[i]) {
+                self.arr[i] = cell;
+                count += 1;
+            }
+        }
+
+        count
+    }
+
     /// Sets the column header at the specified index to the specified string.
     ///
     /// # Panics
@@ -1702,6 +2171,29 @@ impl Table {
 
         self.row_header = Some(vec);
     }
+
+    /// Returns a copy of this 'table' with its rows and columns swapped, meaning the 'cell' at
+    /// (row, col) is moved to (col, row). The row headers become the column headers and the
+    /// column headers become the row headers.
+    #[allow(dead_code)]
+    pub fn transpose(&self) -> Table {
+        let mut table: Table = Table {
+            arr: Vec::with_capacity(self.arr.len()),
+            col_header: self.row_header.clone(),
+            cols: self.rows,
+            row_header: self.col_header.clone(),
+            rows: self.cols,
+        };
+
+        // Copy each column of this table into a row of the new table.
+        for i in 0..self.cols {
+            for j in 0..self.rows {
+                table.arr.push(self.arr[i + (j * self.cols)].clone());
+            }
+        }
+
+        table
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1949,9 +2441,10 @@ impl GridCollection<f32> for AdjacencyMatrix {
             panic!("Cannot insert column into adjacency matrix due to out-of-bounds column index.");
         }
 
-        // Panic if the number of values does not match the row count.
-        if val.len() > self.rows {
-            panic!("Cannot insert column into adjacency matrix due to invalid vector length.");
+        // Panic if the number of values does not match the row count. If there are
+        // no rows, a single value is expected since a row is added below.
+        if val.len() != self.rows.max(1) {
+            panic!("Cannot insert column into adjacency matrix due to vector length not matching row count.");
         }
 
         // If there are no rows, add a row.
@@ -2007,9 +2500,10 @@ impl GridCollection<f32> for AdjacencyMatrix {
             panic!("Cannot insert row into adjacency matrix due to out-of-bounds row index.");
         }
 
-        // Panic if the number of values does not match the column count.
-        if val.len() > self.cols {
-            panic!("Cannot insert row into adjacency matrix due to invalid vector length.");
+        // Panic if the number of values does not match the column count. If there are
+        // no columns, a single value is expected since a column is added below.
+        if val.len() != self.cols.max(1) {
+            panic!("Cannot insert row into adjacency matrix due to vector length not matching column count.");
         }
 
         // If there are no columns, add a column.
@@ -2163,14 +2657,11 @@ impl GridCollection<f32> for AdjacencyMatrix {
 
 // AdjacencyMatrix functions
 impl AdjacencyMatrix {
-    /// Creates a new empty 'adjacency matrix'.
+    /// Creates a new 'adjacency matrix' for the specified number of 'nodes', meaning it has that
+    /// many rows and columns, with no 'edges'. An 'adjacency matrix' for 0 'nodes' is empty.
     #[allow(dead_code)]
-    pub fn new() -> Self {
-        AdjacencyMatrix {
-            arr: Vec::new(),
-            cols: 0,
-            rows: 0,
-        }
+    pub fn new(n: usize) -> Self {
+        AdjacencyMatrix::new_size(n, n)
     }
 
     /// Creates a new 'adjacency matrix' with the specified number of rows and columns that have
@@ -2252,6 +2743,35 @@ impl AdjacencyMatrix {
         }
     }
 
+    /// Returns the degree of the specified 'node', or None if the 'node' does not exist. The
+    /// degree of a 'node' is the number of non-zero elements in its row.
+    #[allow(dead_code)]
+    pub fn degree_of(&self, i: usize) -> Option<usize> {
+        // If the node does not exist, return None.
+        if i >= self.rows {
+            return None;
+        }
+
+        let mut degree: usize = 0;
+
+        for j in 0..self.cols {
+            if self.arr[j + (i * self.cols)] != 0.0 { degree += 1; }
+        }
+
+        Some(degree)
+    }
+
+    /// Returns the weight of the 'edge' from 'node' i to 'node' j. Returns 0 if there is no such
+    /// 'edge' or if either 'node' does not exist.
+    #[allow(dead_code)]
+    pub fn edge(&self, i: usize, j: usize) -> f32 {
+        if i >= self.rows || j >= self.cols {
+            return 0.0;
+        }
+
+        self.arr[j + (i * self.cols)]
+    }
+
     /// Returns the number of 'edges' in this 'adjacency matrix'. A value in this 'adjacency
     /// matrix' is considered an 'edge' if it is not 0.
     pub fn edges(&self) -> usize {
@@ -2264,6 +2784,49 @@ impl AdjacencyMatrix {
         edges
     }
 
+    /// Returns true if this 'adjacency matrix' is square and the element at (i, j) equals the
+    /// element at (j, i) for every i and j, meaning it represents an undirected 'graph'.
+    #[allow(dead_code)]
+    pub fn is_symmetric(&self) -> bool {
+        // If the adjacency matrix is not square, return false.
+        if self.rows != self.cols {
+            return false;
+        }
+
+        // If an element does not match its mirrored element, return false.
+        for i in 0..self.rows {
+            for j in (i + 1)..self.cols {
+                if self.arr[j + (i * self.cols)] != self.arr[i + (j * self.cols)] {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns an 'adjacency list' representing the same 'edges' as this 'adjacency matrix'. The
+    /// 'linked list' at index i contains j for every element (i, j) that is not 0.
+    #[allow(dead_code)]
+    pub fn to_adjacency_list(&self) -> AdjacencyList<usize> {
+        let mut alist: AdjacencyList<usize> = AdjacencyList::new();
+
+        // Add a linked list of connected nodes for each row.
+        for i in 0..self.rows {
+            let mut list: LinkedList<usize> = LinkedList::new();
+
+            for j in 0..self.cols {
+                if self.arr[j + (i * self.cols)] != 0.0 {
+                    list.append(j);
+                }
+            }
+
+            alist.append(&list);
+        }
+
+        alist
+    }
+
     /// Removes the row and column belonging to the specified 'node'. Returns true if successful.
     pub fn remove_node(&mut self, node: usize) -> bool {
         if node < self.cols {
@@ -2274,4 +2837,20 @@ impl AdjacencyMatrix {
 
         false
     }
+
+    /// Sets the weight of the 'edge' from 'node' i to 'node' j to the specified weight. A
+    /// weight of 0 removes the 'edge'.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if either 'node' does not exist.
+    #[allow(dead_code)]
+    pub fn set_edge(&mut self, i: usize, j: usize, w: f32) {
+        // Panic if either node is out-of-bounds.
+        if i >= self.rows || j >= self.cols {
+            panic!("Cannot set adjacency matrix edge due to out-of-bounds node.");
+        }
+
+        self.arr[j + (i * self.cols)] = w;
+    }
 }
\ No newline at end of file
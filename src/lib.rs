@@ -1,3 +1,13 @@
+//! # Structures
+//!
+//! Contains various data structure implementations. The 'std' feature is enabled by default.
+//! Without it, this crate is 'no_std' and only depends on 'core' and 'alloc'. The following
+//! require the 'std' feature: HashSet, HashMap, the 'tree' module (Tree and BinaryTree), the
+//! date/time 'table cell' types, and 'Pos::dist_from'. Every other collection is 'no_std'
+//! clean.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
 extern crate alloc;
 extern crate core;
 
@@ -10,7 +20,19 @@ pub mod map;
 pub mod grid;
 pub mod supers;
 
-#[cfg(test)]
+/// The 'alloc' items that the standard prelude provides, so they are also available to the
+/// crate's modules when built without the 'std' feature.
+#[allow(unused_imports)]
+mod prelude {
+    pub use alloc::borrow::ToOwned;
+    pub use alloc::boxed::Box;
+    pub use alloc::format;
+    pub use alloc::string::{String, ToString};
+    pub use alloc::vec;
+    pub use alloc::vec::Vec;
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use chrono::DateTime;
     use crate::collection::*;
@@ -28,6 +50,7 @@ mod tests {
     use crate::queue::*;
     use crate::set::*;
     use crate::stack::*;
+    use crate::stack::eval::*;
     use crate::supers::*;
     use crate::map::traversable::*;
     use crate::map::traversable::tree::*;
@@ -63,6 +86,53 @@ mod tests {
         assert!(!alist1.contains_all(&vec![kv!(0, 4), kv!(1, 5), kv!(2, 6)]));
         alist1.set(1, &LinkedList::from_vec(&vec![4, 5, 6]));
         assert!(alist1.contains_all(&vec![kv!(0, 4), kv!(1, 5), kv!(2, 6)]));
+        assert_eq!(alist1.neighbors(1), Some(vec![4, 5, 6]));
+        assert_eq!(alist1.neighbors(2), None);
+        assert_eq!(alist1.edge_count(), 6);
+        let mut alist3: AdjacencyList<usize> = AdjacencyList::new();
+        alist3.append(&LinkedList::from_vec(&vec![1, 2]));
+        alist3.append(&LinkedList::from_vec(&vec![2]));
+        alist3.append(&LinkedList::from_vec(&vec![0]));
+        let amtx: AdjacencyMatrix = alist3.to_matrix();
+        assert_eq!(amtx[(0, 1)], 1.0);
+        assert_eq!(amtx[(1, 0)], 0.0);
+        assert_eq!(amtx.edges(), alist3.edge_count());
+        assert_eq!(amtx.to_adjacency_list(), alist3);
+        alist3.get_mut(1).unwrap().append(0);
+        assert_eq!(alist3.neighbors(1), Some(vec![2, 0]));
+        assert!(alist3.get_mut(3).is_none());
+    }
+
+    #[test]
+    fn adjacencymatrix_test() {
+        let mut amtx1: AdjacencyMatrix = AdjacencyMatrix::new(4);
+        assert!(AdjacencyMatrix::new(0).is_empty());
+        assert_eq!(amtx1.rows(), 4);
+        assert_eq!(amtx1.columns(), 4);
+        assert_eq!(amtx1.edges(), 0);
+        assert!(amtx1.is_symmetric());
+        amtx1.set_edge(0, 1, 2.5);
+        assert!(!amtx1.is_symmetric());
+        amtx1.set_edge(1, 0, 2.5);
+        amtx1.set_edge(1, 2, 1.0);
+        amtx1.set_edge(2, 1, 1.0);
+        amtx1.set_edge(1, 3, 4.0);
+        amtx1.set_edge(3, 1, 4.0);
+        assert!(amtx1.is_symmetric());
+        assert_eq!(amtx1.edge(0, 1), 2.5);
+        assert_eq!(amtx1.edge(3, 1), 4.0);
+        assert_eq!(amtx1.edge(0, 3), 0.0);
+        assert_eq!(amtx1.edge(0, 4), 0.0);
+        assert_eq!(amtx1.degree_of(0), Some(1));
+        assert_eq!(amtx1.degree_of(1), Some(3));
+        assert_eq!(amtx1.degree_of(2), Some(1));
+        assert_eq!(amtx1.degree_of(3), Some(1));
+        assert_eq!(amtx1.degree_of(4), None);
+        assert_eq!(amtx1.edges(), 6);
+        amtx1.set_edge(1, 3, 0.0);
+        assert!(!amtx1.is_symmetric());
+        assert_eq!(amtx1.degree_of(1), Some(2));
+        assert!(!AdjacencyMatrix::new_size(2, 3).is_symmetric());
     }
 
     #[test]
@@ -113,6 +183,43 @@ mod tests {
         let mut arr2: Array<i8, 10> = Array::from_vec(&arr1.to_vec());
         assert_eq!(arr1, arr2);
         println!("Reversed: {:?}", arr2.reverse());
+        let mut recs: Array<(i32, i8), 4> =
+            Array::from_vec(&vec![(30, 1), (10, 2), (40, 3), (20, 4)]);
+        recs.sort();
+        assert_eq!(recs.binary_search_by_key(&20, |r| r.0), Ok(1));
+        assert_eq!(recs.binary_search_by_key(&25, |r| r.0), Err(2));
+        assert_eq!(recs.binary_search_by_key(&50, |r| r.0), Err(4));
+        let arr3: Array<i8, 3> = Array::from_vec(&vec![1, 2, 3]);
+        assert!(arr3 < Array::from_vec(&vec![1, 2, 4]));
+        assert_eq!(arr3.cmp(&arr3.clone()), core::cmp::Ordering::Equal);
+        let arr4: Array<i8, 4> = Array::from_vec(&vec![1, 2, 3, 4]);
+        assert!(arr4.contains_subslice(&[2, 3]));
+        assert!(!arr4.contains_subslice(&[2, 4]));
+        assert!(!arr4.contains_subslice(&[1, 2, 3, 4, 5]));
+        let arr5: Array<i8, 10> = Array::from_vec(&vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let (chunks1, rem1) = arr5.chunks_exact(3);
+        assert_eq!(chunks1, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]);
+        assert_eq!(rem1, vec![9]);
+        let mut arr6: Array<i8, 4> = Array::from_vec(&vec![1, 2, 3, 4]);
+        assert_eq!(arr6.shift_in(5), 4);
+        assert_eq!(arr6.shift_in(6), 3);
+        assert_eq!(arr6.shift_in(7), 2);
+        assert_eq!(arr6.to_vec(), vec![7, 6, 5, 1]);
+        let mut arr7: Array<i8, 0> = Array::new();
+        assert_eq!(arr7.shift_in(1), 1);
+        let arr8: Array<i8, 4> = Array::from_vec(&vec![0, 1, 2, 3]);
+        assert_eq!(arr8.iter_rev().collect::<Vec<&i8>>(), vec![&3, &2, &1, &0]);
+        assert_eq!(arr8.to_vec(), vec![0, 1, 2, 3]);
+        let arr9: Array<f64, 3> = Array::from_vec(&vec![0.1 + 0.2, 1.0, 2.0]);
+        let arr10: Array<f64, 3> = Array::from_vec(&vec![0.3, 1.0, 2.0]);
+        assert!(arr9.approx_eq(&arr10, 1e-6));
+        assert!(!arr9.approx_eq(&Array::from_vec(&vec![0.3, 1.1, 2.0]), 1e-6));
+        let arr11: Array<f64, 3> = Array::from_vec(&vec![f64::NAN, 1.0, 2.0]);
+        assert!(!arr11.approx_eq(&arr11, 1e-6));
+        assert!(!arr10.approx_eq(&arr11, 1e-6));
+        let arr12: Array<f64, 3> = Array::from_vec(&vec![f64::INFINITY, 1.0, 2.0]);
+        assert!(arr12.approx_eq(&arr12, 1e-6));
+        assert!(!arr12.approx_eq(&Array::from_vec(&vec![f64::NEG_INFINITY, 1.0, 2.0]), 1e-6));
     }
 
     #[test]
@@ -264,6 +371,171 @@ mod tests {
         let tree3: BinaryTree<i32, i8, true> = BinaryTree::from_vec(&tree1.clone().to_vec());
         assert!(tree1.contains_all(&tree3.to_vec()));
         println!("Path: {:?}", tree1.path_of(400, 10));
+        let tree3: BinaryTree<i32, String, true> = tree1.map_values(|v| v.to_string());
+        assert_eq!(tree3.len(), tree1.len());
+        assert_eq!(tree3[110], str!("9"));
+        let mut trav1 = tree1.clone().into_trav();
+        let mut trav3 = tree3.clone().into_trav();
+        while trav1.has_next() {
+            assert_eq!(trav3.next(), trav1.next().map(|v| v.to_string()));
+        }
+        assert!(!trav3.has_next());
+        let mut tree4: BinaryTree<i32, i8, false> = BinaryTree::new();
+        tree4.insert(kv!(4, 1));
+        tree4.insert(kv!(2, 2));
+        tree4.insert(kv!(6, 3));
+        tree4.insert(kv!(1, 4));
+        tree4.insert(kv!(3, 5));
+        assert_eq!(tree4.internal_count(), 2);
+        assert!(tree4.is_full());
+        assert!(tree4.is_complete());
+        tree4.insert(kv!(5, 6));
+        assert!(!tree4.is_full());
+        assert!(tree4.is_complete());
+        let mut tree5: BinaryTree<i32, i8, false> = BinaryTree::new();
+        tree5.insert(kv!(4, 1));
+        tree5.insert(kv!(2, 2));
+        tree5.insert(kv!(6, 3));
+        tree5.insert(kv!(5, 4));
+        tree5.insert(kv!(7, 5));
+        assert!(tree5.is_full());
+        assert!(!tree5.is_complete());
+        tree5.insert(kv!(3, 6));
+        assert!(!tree5.is_full());
+        assert!(!tree5.is_complete());
+        let mut tree11: BinaryTree<i32, i8, false> = BinaryTree::new();
+        for depth in 0..6 {
+            for i in 0..(1 << depth) {
+                let key: i32 = (2 * i + 1) * (32 >> depth);
+                if key != 61 {
+                    tree11.insert(kv!(key, 1));
+                }
+            }
+        }
+        assert!(!tree11.is_complete());
+        assert_eq!(tree4.min_key(), Some(&1));
+        assert_eq!(tree4.max_key(), Some(&6));
+        assert_eq!(tree5.min_key(), Some(&2));
+        assert_eq!(tree5.max_key(), Some(&7));
+        assert_eq!(BinaryTree::<i32, i8, false>::new().min_key(), None);
+        assert_eq!(tree1.min_key(), tree1.to_vec().iter().map(|kv| &kv.key).min());
+        assert_eq!(tree1.max_key(), tree1.to_vec().iter().map(|kv| &kv.key).max());
+        assert!(tree4.contains(&kv!(5, 6)));
+        assert!(!tree4.contains(&kv!(5, 5)));
+        assert_eq!(tree4.to_vec().iter().map(|kv| kv.key).collect::<Vec<i32>>(),
+                   vec![1, 2, 3, 4, 5, 6]);
+        let mut trav2 = tree1.trav();
+        let pairs: Vec<KeyValue<i32, i8>> = tree1.to_vec();
+        for pair in pairs.iter() {
+            assert_eq!(trav2.next(), Some(pair.value));
+        }
+        assert!(!trav2.has_next());
+        let mut tree9: BinaryTree<i32, i32, false> = BinaryTree::new();
+        tree9.insert(kv!(2, 2));
+        tree9.insert(kv!(1, 1));
+        tree9.insert(kv!(3, 3));
+        let mut trav4 = tree9.trav();
+        tree9.clear();
+        assert!(tree9.is_empty());
+        let mut order: Vec<i32> = Vec::new();
+        trav4.postorder();
+        while trav4.has_next() {
+            order.push(trav4.next().expect("Failed to traverse binary tree"));
+        }
+        assert_eq!(order, vec![1, 3, 2]);
+        trav4.diagonal();
+        assert_eq!(trav4.next(), Some(2));
+        assert_eq!(trav4.next(), Some(1));
+        assert_eq!(trav4.next(), Some(3));
+        let mut tree10: BinaryTree<i32, i32, false> = BinaryTree::new();
+        for i in [4, 2, 6, 1, 3, 9, 7, 10, 8] {
+            tree10.insert(kv!(i, i));
+        }
+        let mut trav5 = tree10.trav();
+        let mut order: Vec<i32> = Vec::new();
+        trav5.boundary();
+        while trav5.has_next() {
+            order.push(trav5.next().expect("Failed to traverse binary tree"));
+        }
+        assert_eq!(order, vec![4, 2, 1, 3, 8, 10, 9, 7, 6]);
+        let mut order: Vec<i32> = Vec::new();
+        trav5.diagonal();
+        while trav5.has_next() {
+            order.push(trav5.next().expect("Failed to traverse binary tree"));
+        }
+        assert_eq!(order, vec![4, 2, 3, 9, 6, 1, 10, 7, 8]);
+        let tree6: &BinaryTree<i32, i8, false> = &tree4;
+        let sub2: BinaryTree<i32, i8, false> = tree6.subtree(2);
+        assert_eq!(sub2.root_node(), Some(&2));
+        assert!(sub2.exists(1) && sub2.exists(3));
+        assert!(!sub2.exists(4) && !sub2.exists(6));
+        let mut tree7: BinaryTree<i32, i32, false> = BinaryTree::new();
+        for i in 0..10000 {
+            tree7.insert(kv!(i, i));
+        }
+        assert_eq!(tree7.height(), 9999);
+        let mut trav3 = tree7.trav();
+        for i in 0..10000 {
+            assert_eq!(trav3.next(), Some(i));
+        }
+        assert!(!trav3.has_next());
+        trav3.postorder();
+        assert_eq!(trav3.next(), Some(9999));
+        assert!(tree7.insert(kv!(-1, -1)));
+        assert_eq!(tree7.min_key(), Some(&-1));
+        assert_eq!(tree7.max_key(), Some(&9999));
+        assert_eq!(tree4.levels(), vec![vec![1], vec![2, 3], vec![4, 5, 6]]);
+        assert_eq!(tree4.to_ascii(), concat!("4: 1\n├── (L) 2: 2\n│   ├── (L) 1: 4\n",
+            "│   └── (R) 3: 5\n└── (R) 6: 3\n    └── (L) 5: 6\n"));
+        assert_eq!(tree9.to_ascii(), "");
+        tree9.insert(kv!(1, 1));
+        tree9.insert(kv!(2, 2));
+        assert_eq!(tree9.to_ascii(), "1: 1\n└── (R) 2: 2\n");
+        let graph1: UUGraph<i8> = tree4.to_graph();
+        assert_eq!(graph1.capacity(), tree4.len());
+        assert_eq!(graph1.edges(), tree4.len() - 1);
+        assert!(graph1.is_connected());
+        assert!(graph1.is_neighbor(0, 1) && graph1.is_neighbor(1, 0));
+        assert_eq!(graph1.get(0), tree4.root_node());
+        for key in [2, 3, 4, 5, 6, 7] {
+            let rank: usize = tree5.rank(&key).unwrap();
+            assert_eq!(rank, key as usize - 2);
+            assert_eq!(tree5.select(rank), Some(&key));
+        }
+        assert_eq!(tree5.rank(&8), Some(6));
+        assert_eq!(tree5.rank(&1), Some(0));
+        assert_eq!(BinaryTree::<i32, i8, false>::new().rank(&1), Some(0));
+        assert_eq!(tree5.select(6), None);
+        assert_eq!(BinaryTree::<i32, i8, false>::new().select(0), None);
+        let mut tree8: BinaryTree<i32, i8, false> = BinaryTree::new();
+        for key in [300, 100, 400, 200, 500] {
+            tree8.insert(kv!(key, 1));
+        }
+        assert_eq!(tree8.floor(&250), Some(&200));
+        assert_eq!(tree8.ceiling(&250), Some(&300));
+        assert_eq!(tree8.floor(&400), Some(&400));
+        assert_eq!(tree8.ceiling(&400), Some(&400));
+        assert_eq!(tree8.floor(&50), None);
+        assert_eq!(tree8.ceiling(&550), None);
+        assert_eq!(tree8.floor(&999), Some(&500));
+        assert_eq!(tree8.rank(&250), Some(2));
+    }
+
+    #[test]
+    fn default_test() {
+        assert_eq!(List::<i8>::default(), List::new());
+        assert_eq!(Vector::<i8>::default(), Vector::new());
+        assert_eq!(Queue::<i8>::default(), Queue::new());
+        assert_eq!(Deque::<i8>::default(), Deque::new());
+        assert_eq!(Stack::<i8>::default(), Stack::new());
+        assert_eq!(Set::<i8>::default(), Set::new());
+        assert_eq!(HashSet::<i8>::default(), HashSet::new());
+        assert_eq!(Map::<i32, i8>::default(), Map::new());
+        assert_eq!(HashMap::<i32, i8>::default(), HashMap::new());
+        assert_eq!(Dictionary::<i8>::default(), Dictionary::new());
+        assert_eq!(Grid::<i8>::default(), Grid::new());
+        let queue1: Queue<i8> = Default::default();
+        assert_eq!(queue1.capacity(), Queue::<i8>::new().capacity());
     }
 
     #[test]
@@ -308,6 +580,90 @@ mod tests {
         let deq5: Deque<i8> = Deque::with_capacity(20);
         assert_eq!(deq5.capacity(), 20);
         println!("Reversed: {:?}", deq3.reverse());
+        let mut deque5: Deque<i8> = (0..5).collect();
+        assert_eq!(deque5.to_vec(), vec![0, 1, 2, 3, 4]);
+        deque5.extend(5..7);
+        assert_eq!(deque5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        let mut deq6: Deque<i8> = Deque::from_vec(&vec![1, 2, 3]);
+        *deq6.peek_mut().expect("Deque peek mut failed.") += 10;
+        assert_eq!(deq6.dequeue(), Some(11));
+        assert_eq!(deq6.peek(), Some(&2));
+        assert_eq!(Deque::<i8>::new().peek_mut(), None);
+        let mut deq7: Deque<i8> = (0..12).collect();
+        let orig7: Vec<i8> = deq7.to_vec();
+        assert_eq!(deq7.reverse().to_vec(), (0..12).rev().collect::<Vec<i8>>());
+        assert_eq!(deq7.to_vec(), orig7);
+        deq7.reverse_in_place();
+        assert_eq!(deq7.peek(), Some(&11));
+        assert_eq!(deq7.peek_last(), Some(&0));
+        deq7.reverse_in_place();
+        assert_eq!(deq7.to_vec(), orig7);
+        let deq8: Deque<i8> = Deque::from(vec![1, 2, 3]);
+        assert_eq!(deq8.peek(), Some(&1));
+        assert_eq!(deq8.peek_last(), Some(&3));
+        let vec8: Vec<i8> = deq8.into();
+        assert_eq!(vec8, vec![1, 2, 3]);
+        let mut deq16: Deque<i8> = Deque::from(vec![1, 2, 3]);
+        assert!(!deq16.is_full());
+        assert!(deq16.enqueue(4));
+        assert_eq!(deq16.pop_last(), Some(4));
+        let mut deq9: Deque<i8> = Deque::with_capacity(4);
+        deq9.enqueue(1);
+        deq9.enqueue(2);
+        deq9.enqueue(3);
+        deq9.dequeue();
+        deq9.push(1);
+        deq9.push(0);
+        let (front9, back9) = deq9.as_slices();
+        assert_eq!([front9, back9].concat(), deq9.to_vec());
+        assert_eq!(deq9.to_vec(), vec![0, 1, 2, 3]);
+        let deq10: Deque<i8> = Deque::from(vec![1, 2]);
+        assert_eq!(deq10.as_slices(), (&[1i8, 2][..], &[][..]));
+        let mut deq11: Deque<i8> = Deque::new();
+        deq11.enqueue_all(vec![1, 2, 3, 4, 5]);
+        assert_eq!(deq11.dequeue_n(3), vec![1, 2, 3]);
+        assert_eq!(deq11.len(), 2);
+        assert_eq!(deq11.peek_last(), Some(&5));
+        assert_eq!(deq11.dequeue_n(5), vec![4, 5]);
+        assert!(deq11.dequeue_n(1).is_empty());
+        let deq12: Deque<i8> = Deque::from(vec![1, 2, 3]);
+        let mut stack12: Stack<i8> = deq12.clone().into_stack();
+        assert_eq!(stack12.peek_top(), Some(&3));
+        assert_eq!(stack12.pop(), Some(3));
+        assert!(stack12.push(3) && stack12.push(4));
+        let mut queue12: Queue<i8> = deq12.into_queue();
+        assert_eq!(queue12.peek(), Some(&1));
+        assert_eq!(queue12.dequeue(), Some(1));
+        assert_eq!(queue12.len(), 2);
+        assert!(queue12.enqueue(4) && queue12.enqueue(5));
+        let mut deq13: Deque<i32> = Deque::new();
+        deq13.enqueue_all((0..1000).collect());
+        deq13.dequeue_n(995);
+        let cap13: usize = deq13.capacity();
+        deq13.shrink_to_fit();
+        assert!(deq13.capacity() < cap13);
+        assert!(deq13.capacity() >= deq13.len());
+        assert_eq!(deq13.peek_last(), Some(&999));
+        let mut deq14: Deque<i32> = Deque::new();
+        deq14.enqueue_all(vec![2, 3]);
+        deq14.push(1);
+        assert_eq!(deq14.drain(), vec![1, 2, 3]);
+        assert!(deq14.is_empty());
+        assert!(deq14.drain().is_empty());
+        let mut deq15: Deque<i32> = Deque::with_capacity(4);
+        deq15.enqueue(1);
+        deq15.enqueue(2);
+        deq15.enqueue(3);
+        deq15.dequeue();
+        deq15.dequeue();
+        deq15.enqueue(4);
+        deq15.enqueue(5);
+        deq15.push(0);
+        assert_eq!(deq15.peek_at(0), Some(&0));
+        assert_eq!(deq15.peek_at(1), Some(&3));
+        assert_eq!(deq15.peek_at(3), Some(&5));
+        assert_eq!(deq15.peek_at(4), None);
+        assert_eq!(deq15.len(), 4);
     }
 
     #[test]
@@ -355,6 +711,17 @@ mod tests {
         assert!(dict1.is_sorted_rev());
         let dict3: Dictionary<i8> = Dictionary::from_vec(&dict1.to_vec());
         assert_eq!(dict1, dict3);
+        let mut dict4: Dictionary<i32> = Dictionary::new();
+        dict4.insert(dkv!("ann", 12));
+        dict4.insert(dkv!("bob", 30));
+        dict4.insert(dkv!("cat", 7));
+        dict4.insert(dkv!("dan", 30));
+        dict4.insert(dkv!("eve", 21));
+        dict4.insert(dkv!("fay", 3));
+        assert_eq!(dict4.top_n(3), vec![dkv!("bob", 30), dkv!("dan", 30), dkv!("eve", 21)]);
+        assert_eq!(dict4.top_n(1), vec![dkv!("bob", 30)]);
+        assert_eq!(dict4.top_n(10).len(), 6);
+        assert!(dict4.top_n(0).is_empty());
     }
 
     #[test]
@@ -402,11 +769,36 @@ mod tests {
         dlist3 = DoublyLinkedList::circular_from_vec(&vec![0, 1, 2, 3]);
         assert!(dlist3.is_circular());
         assert!(dlist3.contains_all(&vec![kv!(0, 0), kv!(1, 1), kv!(2, 2), kv!(3, 3)]));
-        dlist3 = DoublyLinkedList::from_vec(&vec![0, 1, 2, 3, 4]);
+        dlist3 = DoublyLinkedList::from_vec(&[0, 1, 2, 3, 4]);
         assert!(!dlist3.is_circular());
         assert!(dlist3.contains_all(&vec![kv!(0, 0), kv!(1, 1), kv!(2, 2), kv!(3, 3), kv!(4, 4)]));
         println!("Reversed: {:?}", dlist3.reverse());
         println!("Path: {:?}", dlist3.path_of(1, 3));
+        let mut dlist9: DoublyLinkedList<i8> = DoublyLinkedList::from_vec(&[0, 1, 2, 3, 4]);
+        assert!(dlist9.swap(0, 3));
+        assert_eq!(dlist9.get(0), Some(&3));
+        assert_eq!(dlist9.get(3), Some(&0));
+        assert_eq!(dlist9.to_vec(), vec![kv!(0, 3), kv!(1, 1), kv!(2, 2), kv!(3, 0), kv!(4, 4)]);
+        assert!(dlist9.is_neighbor(1, 0) && dlist9.is_neighbor(2, 3) && dlist9.is_neighbor(3, 4));
+        assert_eq!(dlist9.edges(), 4);
+        assert!(!dlist9.swap(0, 5));
+    }
+
+    #[test]
+    fn eval_test() {
+        let tokens: Vec<&str> = "3 4 + 5 *".split_whitespace().collect();
+        assert_eq!(eval_rpn(&tokens), Ok(35.0));
+        assert_eq!(eval_rpn(&["10", "4", "-", "2", "/"]), Ok(3.0));
+        assert_eq!(eval_rpn(&["1.5", "-2", "*"]), Ok(-3.0));
+        assert_eq!(eval_rpn(&["3", "+"]), Err(EvalError::MissingOperand(1)));
+        assert_eq!(eval_rpn(&["3", "x", "+"]), Err(EvalError::InvalidToken(1)));
+        assert_eq!(eval_rpn(&["1", "0", "/"]), Err(EvalError::DivisionByZero(2)));
+        assert_eq!(eval_rpn(&["1", "2"]), Err(EvalError::TooManyOperands));
+        assert_eq!(eval_rpn(&[]), Err(EvalError::EmptyExpression));
+        let long: Vec<String> = (0..20).map(|i| i.to_string()).collect();
+        let mut long_tokens: Vec<&str> = long.iter().map(|t| t.as_str()).collect();
+        long_tokens.extend(vec!["+"; 19]);
+        assert_eq!(eval_rpn(&long_tokens), Ok(190.0));
     }
 
     #[test]
@@ -574,6 +966,274 @@ mod tests {
         println!("Edge from 0 to 2: {}", g2.edge(&g2.node(0).unwrap(), &g2.node(2).unwrap()));
         assert!(!g2.has_neg_edges());
         println!("Graph Radius: {}", g2.radius());
+        let mut g3: UUGraph<i8> = Graph::new();
+        g3.insert(kv!(0, 1));
+        g3.insert(kv!(1, 2));
+        g3.insert(kv!(2, 3));
+        g3.insert(kv!(3, 4));
+        g3.insert(kv!(4, 5));
+        g3.connect(UUGraphEdge::new(0, 1));
+        g3.connect(UUGraphEdge::new(1, 2));
+        g3.connect(UUGraphEdge::new(3, 4));
+        assert_eq!(g3.eccentricity(&g3.node(0).unwrap()), None);
+        assert_eq!(g3.eccentricity(&g3.node(3).unwrap()), None);
+        assert_eq!(g3.radius(), 1.0);
+        assert_eq!(g3.diameter(), 2.0);
+        let center: Vec<usize> = g3.center().into_iter().map(|n| n.pair.key).collect();
+        assert_eq!(center, vec![1, 3, 4]);
+        g3.connect(UUGraphEdge::new(2, 3));
+        assert_eq!(g3.eccentricity(&g3.node(0).unwrap()), Some(4.0));
+        assert_eq!(g3.eccentricity(&g3.node(2).unwrap()), Some(2.0));
+        assert_eq!(g3.radius(), 2.0);
+        let bfs: Vec<usize> = g3.bfs_order(2);
+        assert_eq!(bfs[0], 2);
+        assert_eq!(bfs, vec![2, 1, 3, 0, 4]);
+        let dfs: Vec<usize> = g3.dfs_order(2);
+        assert_eq!(dfs[0], 2);
+        assert_eq!(dfs, vec![2, 1, 0, 3, 4]);
+        assert_eq!(g1.bfs_order(1), vec![1]);
+        let mut dfs1: Vec<usize> = g1.dfs_order(0);
+        dfs1.sort();
+        assert_eq!(dfs1, vec![0, 1, 2]);
+        assert!(g3.bfs_order(10).is_empty());
+        let path1: Vec<usize> = g1.shortest_path(0, 1).expect("Failed to find shortest path");
+        assert_eq!(path1, vec![0, 2, 1]);
+        let mut weight1: f32 = 0.0;
+        for i in 1..path1.len() {
+            weight1 += g1.edge(&g1.node(path1[i - 1]).unwrap(), &g1.node(path1[i]).unwrap());
+        }
+        assert_eq!(Some(weight1), g1.distance(&g1.node(0).unwrap(), &g1.node(1).unwrap()));
+        assert_eq!(g1.shortest_path(1, 0), None);
+        assert_eq!(g3.shortest_path(0, 4), Some(vec![0, 1, 2, 3, 4]));
+        assert_eq!(g3.shortest_path(2, 2), Some(vec![2]));
+        let mut g4: UWGraph<i8> = Graph::new();
+        g4.insert(kv!(0, 1));
+        g4.insert(kv!(1, 2));
+        g4.insert(kv!(2, 3));
+        g4.connect(UWGraphEdge::new(0, 1, 1.0));
+        g4.connect(UWGraphEdge::new(1, 2, 1.0));
+        g4.connect(UWGraphEdge::new(0, 2, 2.0));
+        assert_eq!(g4.shortest_path(0, 2), Some(vec![0, 2]));
+        assert_eq!(Some(2.0), g4.distance(&g4.node(0).unwrap(), &g4.node(2).unwrap()));
+        let mut g5: UUGraph<i8> = Graph::new();
+        for i in 0..4 {
+            g5.insert(kv!(i, (i as i8)));
+        }
+        g5.connect(UUGraphEdge::new(0, 1));
+        g5.connect(UUGraphEdge::new(1, 2));
+        g5.connect(UUGraphEdge::new(2, 3));
+        g5.connect(UUGraphEdge::new(3, 0));
+        let colors5: Map<usize, usize> = g5.is_k_colorable(2).expect("Failed to 2-color graph");
+        assert_eq!(colors5.len(), 4);
+        for e in g5.edge_list() {
+            assert_ne!(colors5.get(e.node_a), colors5.get(e.node_b));
+        }
+        assert!(g5.is_k_colorable(1).is_none());
+        let mut g6: UUGraph<i8> = Graph::new();
+        for i in 0..5 {
+            g6.insert(kv!(i, (i as i8)));
+        }
+        for i in 0..5 {
+            g6.connect(UUGraphEdge::new(i, (i + 1) % 5));
+        }
+        assert!(g6.is_k_colorable(2).is_none());
+        let colors6: Map<usize, usize> = g6.is_k_colorable(3).expect("Failed to 3-color graph");
+        for e in g6.edge_list() {
+            assert_ne!(colors6.get(e.node_a), colors6.get(e.node_b));
+        }
+        let (part_a, part_b): (Set<usize>, Set<usize>) = g5.bipartition()
+            .expect("Failed to partition graph");
+        assert_eq!(part_a.len() + part_b.len(), 4);
+        for i in 0..4 {
+            assert!(part_a.contains(&i) != part_b.contains(&i));
+        }
+        for e in g5.edge_list() {
+            assert!(part_a.contains(&e.node_a) != part_a.contains(&e.node_b));
+        }
+        assert!(g6.bipartition().is_none());
+        let g5c: UUGraph<i8> = g5.complement();
+        assert_eq!(g5c.edges(), 2);
+        assert_eq!(g5.edges() + g5c.edges(), 4 * 3 / 2);
+        assert!(g5c.is_neighbor(0, 2) && g5c.is_neighbor(1, 3) && !g5c.is_neighbor(0, 1));
+        assert_eq!(g6.edges() + g6.complement().edges(), 5 * 4 / 2);
+        assert_eq!(g5c.complement(), g5);
+        let layers: Vec<Vec<usize>> = g3.bfs_layers(2);
+        assert_eq!(layers[0], vec![2]);
+        assert_eq!(layers, vec![vec![2], vec![1, 3], vec![0, 4]]);
+        let mut seen: Vec<usize> = layers.concat();
+        seen.sort();
+        assert_eq!(seen, vec![0, 1, 2, 3, 4]);
+        assert_eq!(g5c.bfs_layers(0), vec![vec![0], vec![2]]);
+        assert!(g3.bfs_layers(10).is_empty());
+        let mut g7: UUGraph<i8> = Graph::new();
+        for i in 0..4 {
+            g7.insert(kv!(i, (i as i8)));
+        }
+        for i in 0..4 {
+            for j in (i + 1)..4 {
+                g7.connect(UUGraphEdge::new(i, j));
+            }
+        }
+        assert_eq!(g7.density(), 1.0);
+        assert_eq!(g7.avg_path_length(), Some(1.0));
+        assert_eq!(g5.density(), 4.0 / 6.0);
+        assert_eq!(g5.avg_path_length(), Some(16.0 / 12.0));
+        assert_eq!(g5c.avg_path_length(), None);
+        assert_eq!(UUGraph::<i8>::new().density(), 0.0);
+        assert_eq!(g3.degree_sequence(), vec![2, 2, 2, 1, 1]);
+        let hist: Map<usize, usize> = g3.degree_histogram();
+        assert_eq!(hist.len(), 2);
+        assert_eq!(hist.get(1), Some(&2));
+        assert_eq!(hist.get(2), Some(&3));
+        assert_eq!(g7.degree_sequence(), vec![3, 3, 3, 3]);
+        assert_eq!(g1.degree_sequence().len(), 3);
+        let mut g8: DWGraph<i8> = Graph::new();
+        g8.insert(kv!(0, 1));
+        g8.insert(kv!(1, 2));
+        g8.insert(kv!(2, 3));
+        g8.connect(DWGraphEdge::new(0, 1, 5.0));
+        g8.connect(DWGraphEdge::new(1, 2, 1.0));
+        g8.connect(DWGraphEdge::new(0, 2, 4.0));
+        assert_eq!(g8.distance(&g8.node(0).unwrap(), &g8.node(2).unwrap()), Some(4.0));
+        assert!(g8.set_weight(0, 1, 2.0));
+        assert_eq!(g8.edge(&g8.node(0).unwrap(), &g8.node(1).unwrap()), 2.0);
+        assert_eq!(g8.distance(&g8.node(0).unwrap(), &g8.node(2).unwrap()), Some(3.0));
+        assert!(!g8.set_weight(1, 0, 2.0));
+        assert!(!g8.set_weight(0, 3, 2.0));
+        let mut g9: UWGraph<i8> = Graph::new();
+        g9.insert(kv!(0, 1));
+        g9.insert(kv!(1, 2));
+        g9.connect(UWGraphEdge::new(0, 1, 1.5));
+        assert!(g9.set_weight(1, 0, 3.5));
+        assert_eq!(g9.edge(&g9.node(0).unwrap(), &g9.node(1).unwrap()), 3.5);
+        let mut g10: DUGraph<i8> = Graph::new();
+        for i in 0..5 {
+            g10.insert(kv!(i, (i as i8)));
+        }
+        g10.connect(DUGraphEdge::new(0, 1));
+        g10.connect(DUGraphEdge::new(0, 2));
+        g10.connect(DUGraphEdge::new(1, 3));
+        g10.connect(DUGraphEdge::new(2, 3));
+        g10.connect(DUGraphEdge::new(2, 4));
+        assert!(g10.is_dag());
+        assert_eq!(g10.roots(), vec![0]);
+        assert_eq!(g10.sinks(), vec![3, 4]);
+        g10.connect(DUGraphEdge::new(3, 0));
+        assert!(!g10.is_dag());
+        assert!(g10.roots().is_empty());
+        let mut g11: UUGraph<i8> = Graph::new();
+        for i in 0..5 {
+            g11.insert(kv!(i, (i as i8)));
+        }
+        g11.connect(UUGraphEdge::new(0, 1));
+        g11.connect(UUGraphEdge::new(1, 2));
+        g11.connect(UUGraphEdge::new(2, 0));
+        g11.connect(UUGraphEdge::new(2, 3));
+        g11.connect(UUGraphEdge::new(3, 4));
+        let euler: Vec<usize> = g11.eulerian_path().expect("Failed to find Eulerian path");
+        assert_eq!(euler.len(), g11.edges() + 1);
+        assert!(euler[0] == 4 || euler[euler.len() - 1] == 4);
+        for i in 1..euler.len() {
+            assert!(g11.is_neighbor(euler[i - 1], euler[i]));
+        }
+        let mut used: Vec<(usize, usize)> = Vec::new();
+        for i in 1..euler.len() {
+            let edge: (usize, usize) = (euler[i - 1].min(euler[i]), euler[i - 1].max(euler[i]));
+            assert!(!used.contains(&edge));
+            used.push(edge);
+        }
+        g11.connect(UUGraphEdge::new(1, 3));
+        assert_eq!(g11.eulerian_path(), None);
+        let mut g12: UUGraph<i8> = Graph::new();
+        for i in 0..3 {
+            g12.insert(kv!(i, (i as i8)));
+        }
+        g12.connect(UUGraphEdge::new(0, 1));
+        g12.connect(UUGraphEdge::new(1, 2));
+        g12.connect(UUGraphEdge::new(2, 0));
+        assert_eq!(g12.eulerian_path(), Some(vec![0, 1, 2, 0]));
+        assert_eq!(UUGraph::<i8>::new().eulerian_path(), None);
+        let mut g13: UWGraph<i8> = Graph::new();
+        for i in 0..6 {
+            g13.insert(kv!(i, (i as i8)));
+        }
+        for (a, b) in [(0, 1), (1, 2), (0, 2), (3, 4), (4, 5), (3, 5)] {
+            g13.connect(UWGraphEdge::new(a, b, 3.0));
+        }
+        g13.connect(UWGraphEdge::new(2, 3, 1.0));
+        g13.connect(UWGraphEdge::new(0, 5, 0.5));
+        let (cut13, (part_a, part_b)) = g13.min_cut().unwrap();
+        assert_eq!(cut13, 1.5);
+        assert_eq!(part_a.len(), 3);
+        assert_eq!(part_b.len(), 3);
+        assert_eq!(part_a.contains(&0), part_a.contains(&2));
+        assert_ne!(part_a.contains(&2), part_a.contains(&3));
+        let mut g14: UWGraph<i8> = Graph::new();
+        g14.insert(kv!(0, 1));
+        assert!(g14.min_cut().is_none());
+        let mut g15: DWGraph<i8> = Graph::new();
+        for i in 0..4 {
+            g15.insert(kv!(i, (i as i8)));
+        }
+        g15.connect(DWGraphEdge::new(0, 1, 0.5));
+        g15.connect(DWGraphEdge::new(0, 2, 0.3));
+        g15.connect(DWGraphEdge::new(3, 0, 2.0));
+        assert_eq!(g15.neighbors_weighted(0), Some(vec![(1, 0.5), (2, 0.3)]));
+        assert_eq!(g15.neighbors_weighted(1), Some(vec![]));
+        assert_eq!(g15.neighbors_weighted(4), None);
+        assert_eq!(g13.neighbors_weighted(2), Some(vec![(0, 3.0), (1, 3.0), (3, 1.0)]));
+        let mut g16: UUGraph<i8> = Graph::new();
+        for i in 0..3 {
+            g16.insert(kv!(i, (i as i8)));
+        }
+        g16.connect(UUGraphEdge::new(0, 1));
+        g16.connect(UUGraphEdge::new(1, 2));
+        let mut blocked16: Set<usize> = Set::new();
+        blocked16.add(1);
+        assert_eq!(g16.shortest_path_avoiding(0, 2, &blocked16), None);
+        assert_eq!(g16.shortest_path_avoiding(0, 1, &blocked16), Some(vec![0, 1]));
+        blocked16.remove(1);
+        assert_eq!(g16.shortest_path_avoiding(0, 2, &blocked16), Some(vec![0, 1, 2]));
+        let g17: UUGraph<i8> = g1.to_undirected();
+        assert_eq!(g17.len(), g1.len());
+        assert_eq!(g17.edges(), g1.edges());
+        assert!(!g1.is_connected());
+        assert!(g17.is_connected());
+        assert!(g17.is_neighbor(1, 0) && g17.is_neighbor(1, 2));
+        let mut g18: DUGraph<i8> = Graph::new();
+        g18.insert(kv!(0, 1));
+        g18.insert(kv!(1, 2));
+        g18.connect(DUGraphEdge::new(0, 1));
+        g18.connect(DUGraphEdge::new(1, 0));
+        assert_eq!(g18.edges(), 2);
+        assert_eq!(g18.to_undirected().edges(), 1);
+        let mut g19: UUGraph<i8> = Graph::new();
+        for i in 0..6 {
+            g19.insert(kv!(i, (i as i8)));
+        }
+        g19.connect(UUGraphEdge::new(0, 1));
+        g19.connect(UUGraphEdge::new(0, 2));
+        g19.connect(UUGraphEdge::new(1, 3));
+        g19.connect(UUGraphEdge::new(2, 4));
+        let mut g19t = g19.clone().into_trav();
+        let mut order19: Vec<i8> = Vec::new();
+        g19t.bfs();
+        while g19t.has_next() {
+            order19.push(g19t.next().unwrap());
+        }
+        assert_eq!(order19, vec![0, 1, 2, 3, 4]);
+        order19.clear();
+        g19t.dfs();
+        while g19t.has_next() {
+            order19.push(g19t.next().unwrap());
+        }
+        assert_eq!(order19, vec![0, 2, 4, 1, 3]);
+        order19.clear();
+        g19t.dfs_all();
+        while g19t.has_next() {
+            order19.push(g19t.next().unwrap());
+        }
+        assert_eq!(order19, vec![0, 2, 4, 1, 3, 5]);
     }
 
     #[test]
@@ -636,6 +1296,104 @@ mod tests {
         assert_eq!(grid4.size(), 36);
         let grid5: Grid<i8> = Grid::from_vec(grid1.columns(), grid1.rows(), &grid1.to_vec());
         assert_eq!(grid5, grid1);
+        let grid6: Grid<i8> = Grid::from_vec(2, 3, &vec![1, 2, 3, 4, 10, 6]);
+        assert_eq!(grid6.to_string(), "[ 1,  2,  3]\n[ 4, 10,  6]");
+        let pos1: Pos = Pos::at(5, 5);
+        let pos2: Pos = Pos::at(1, 1);
+        assert_eq!(pos1.dist_from(pos2), 32f64.sqrt());
+        assert_eq!(pos2.dist_from(pos1), 32f64.sqrt());
+        let mut grid7: Grid<i8> = Grid::from_vec(3, 3, &vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        grid7.resize_preserve(4, 4);
+        assert_eq!(grid7.rows(), 4);
+        assert_eq!(grid7.columns(), 4);
+        assert_eq!(grid7.to_vec(), vec![1, 2, 3, 0, 4, 5, 6, 0, 7, 8, 9, 0, 0, 0, 0, 0]);
+        grid7.resize_preserve(2, 2);
+        assert_eq!(grid7.to_vec(), vec![1, 2, 4, 5]);
+        *grid7.get_mut(Pos::at(1, 0)).expect("Failed to get grid value") = 7;
+        assert_eq!(grid7[(1, 0)], 7);
+        assert_eq!(grid7.get_mut(Pos::at(2, 0)), None);
+        assert_eq!(grid7.get_mut(Pos::at(0, 2)), None);
+        let grid8: Grid<i8> = Grid::from_vec(2, 3, &vec![1, 2, 3, 4, 5, 6]);
+        let rows: Vec<Vec<i8>> = grid8.iter_rows();
+        assert_eq!(rows.len(), grid8.rows());
+        for i in 0..rows.len() {
+            assert_eq!(rows[i].len(), grid8.columns());
+            for j in 0..rows[i].len() {
+                assert_eq!(rows[i][j], grid8[(i, j)]);
+            }
+        }
+        assert_eq!(grid8.iter_cols(), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(Grid::<i8>::new().iter_cols(), Vec::<Vec<i8>>::new());
+        let grid9: Grid<i8> = Grid::from_vec(3, 3, &vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(grid9.main_diagonal(), vec![1, 5, 9]);
+        assert_eq!(grid9.anti_diagonal(), vec![3, 5, 7]);
+        assert_eq!(grid9.all_diagonals(), vec![
+            vec![7], vec![4, 8], vec![1, 5, 9], vec![2, 6], vec![3],
+            vec![1], vec![2, 4], vec![3, 5, 7], vec![6, 8], vec![9]]);
+        assert!(grid8.main_diagonal().is_empty());
+        assert!(grid8.anti_diagonal().is_empty());
+        assert_eq!(grid8.all_diagonals(), vec![vec![4], vec![1, 5], vec![2, 6], vec![3],
+            vec![1], vec![2, 4], vec![3, 5], vec![6]]);
+        assert_eq!(grid9.find_positions(|x| *x > 6),
+            vec![Pos::at(2, 0), Pos::at(2, 1), Pos::at(2, 2)]);
+        assert_eq!(grid6.find_positions(|x| *x % 2 == 0),
+            vec![Pos::at(0, 1), Pos::at(1, 0), Pos::at(1, 1), Pos::at(1, 2)]);
+        assert!(grid9.find_positions(|x| *x > 9).is_empty());
+        let grid10: Grid<i8> = Grid::from_vec(3, 3, &vec![1, 2, 1, 0, 1, 2, 2, 2, 1]);
+        assert_eq!(grid10.count(&1), 4);
+        assert_eq!(grid10.count(&2), 4);
+        assert_eq!(grid10.count(&5), 0);
+        assert_eq!(grid10.count_where(|x| *x > 0), 8);
+        assert_eq!(grid10.count_where(|x| *x > 2), 0);
+        let grid11: Grid<i8> = Grid::from_vec(2, 3, &vec![1, 2, 3, 4, 5, 6]);
+        let (parts, rows11, cols11) = grid11.clone().into_parts();
+        assert_eq!(parts, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!((rows11, cols11), (2, 3));
+        let grid12: Grid<i8> = Grid::from_parts(parts, rows11, cols11)
+            .expect("Failed to rebuild grid");
+        assert_eq!(grid12, grid11);
+        assert_eq!(grid12[(1, 0)], 4);
+        assert_eq!(Grid::from_parts(vec![1i8, 2, 3], 2, 2),
+            Err(GridError::LengthMismatch { expected: 4, actual: 3 }));
+        let grid13: Grid<i8> = Grid::from_vec(3, 3, &vec![0, 1, 0, 0, 1, 0, 0, 1, 0]);
+        let life = |cell: &i8, near: &[&i8]| -> i8 {
+            let alive: i8 = near.iter().map(|x| **x).sum();
+            if alive == 3 || (*cell == 1 && alive == 2) { 1 } else { 0 }
+        };
+        let grid14: Grid<i8> = grid13.convolve(life, true);
+        assert_eq!(grid14, Grid::from_vec(3, 3, &vec![0, 0, 0, 1, 1, 1, 0, 0, 0]));
+        assert_eq!(grid14.convolve(life, true), grid13);
+        let grid15: Grid<i8> = grid13.convolve(|_, near| near.len() as i8, false);
+        assert_eq!(grid15, Grid::from_vec(3, 3, &vec![2, 3, 2, 3, 4, 3, 2, 3, 2]));
+        assert_eq!(grid13.convolve(|_, near| near.len() as i8, true)[(1, 1)], 8);
+        let grid16: Grid<f64> = Grid::from_vec(2, 2, &vec![0.1 + 0.2, 1.0, 2.0, 3.0]);
+        let grid17: Grid<f64> = Grid::from_vec(2, 2, &vec![0.3, 1.0, 2.0, 3.0]);
+        assert!(grid16.approx_eq(&grid17, 1e-6));
+        assert!(!grid16.approx_eq(&Grid::from_vec(1, 4, &vec![0.3, 1.0, 2.0, 3.0]), 1e-6));
+        let grid18: Grid<f64> = Grid::from_vec(2, 2, &vec![0.3, f64::NAN, 2.0, 3.0]);
+        assert!(!grid18.approx_eq(&grid18, 1e-6));
+        assert!(!grid17.approx_eq(&grid18, 1e-6));
+        let grid19: Grid<f64> = Grid::from_vec(2, 2, &vec![0.3, 1.0, f64::INFINITY, 3.0]);
+        assert!(grid19.approx_eq(&grid19, 1e-6));
+        assert!(!grid19.approx_eq(&Grid::from_vec(2, 2, &vec![0.3, 1.0, 2.0, 3.0]), 1e-6));
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot insert column into grid due to vector length")]
+    fn grid_insert_col_val_test() {
+        let mut grid1: Grid<i8> = Grid::new_size(3, 3);
+        grid1.insert_col_val(1, &vec![1, 2, 3]);
+        assert_eq!(grid1.get_col(1), Some(vec![1, 2, 3]));
+        grid1.insert_col_val(1, &vec![1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot insert row into grid due to vector length")]
+    fn grid_insert_row_val_test() {
+        let mut grid1: Grid<i8> = Grid::new_size(3, 3);
+        grid1.insert_row_val(1, &vec![1, 2, 3]);
+        assert_eq!(grid1.get_row(1), Some(vec![1, 2, 3]));
+        grid1.insert_row_val(1, &vec![1, 2]);
     }
 
     #[test]
@@ -649,6 +1407,10 @@ mod tests {
         assert!(hmap1.capacity() > 0);
         assert!(hmap1.contains(&kv!(1, 1.5)));
         assert!(hmap1.contains_all(&vec![kv!(0, 3.2), kv!(1, 1.5), kv!(2, 6.7)]));
+        assert!(hmap1.contains_key(&4));
+        assert!(!hmap1.contains_key(&5));
+        assert!(hmap1.contains_value(&6.7));
+        assert!(!hmap1.contains_value(&9.9));
         assert_eq!(hmap1, hmap1.clone());
         assert_eq!(hmap1.len(), 5);
         for i in hmap1.clone().into_iter() {
@@ -664,6 +1426,24 @@ mod tests {
         assert!(hmap1.replace(kv!(2, 3.8)));
         let hmap2: HashMap<i32, f32> = HashMap::from_vec(&hmap1.to_vec());
         assert_eq!(hmap1, hmap2);
+        let mut hmap5: HashMap<i32, i8> = (0..3).map(|i| kv!(i, i as i8)).collect();
+        assert_eq!(hmap5.len(), 3);
+        hmap5.extend(vec![kv!(2, 5), kv!(3, 3)]);
+        assert_eq!(hmap5.len(), 4);
+        assert_eq!(hmap5.get(2), Some(&2));
+        assert_eq!(hmap5.get(3), Some(&3));
+        let mut hmap6: HashMap<i32, i8> = HashMap::with_capacity(100);
+        assert!(hmap6.capacity() >= 100);
+        for i in 0..50 {
+            assert!(hmap6.insert(kv!(i, i as i8)));
+        }
+        assert!(hmap6.capacity() >= 100);
+        assert_eq!(hmap6.len(), 50);
+        for i in 0..50 {
+            assert_eq!(hmap6.get(i), Some(&(i as i8)));
+        }
+        hmap6.reserve(200);
+        assert!(hmap6.capacity() >= 250);
     }
 
     #[test]
@@ -695,6 +1475,30 @@ mod tests {
         }
         println!();
         assert!(hset1 == hset1);
+        let mut hset5: HashSet<i8> = (0..5).collect();
+        assert_eq!(hset5.len(), 5);
+        hset5.extend(3..7);
+        assert_eq!(hset5.len(), 7);
+        assert!(hset5.contains_all(&vec![0, 1, 2, 3, 4, 5, 6]));
+        let hset6: HashSet<i32> = (0..5).collect();
+        assert_eq!(hset6.len(), 5);
+        let mut sum: i32 = 0;
+        for i in hset6.iter() {
+            sum += *i;
+        }
+        assert_eq!(sum, 10);
+        assert!(hset6.iter().all(|i| hset6.contains(i)));
+        let mut hset7: HashSet<i8> = HashSet::from_vec(&vec![1, 2, 3]);
+        assert_eq!(hset7.take(&2), Some(2));
+        assert_eq!(hset7.take(&2), None);
+        assert!(!hset7.contains(&2));
+        assert!(hset7.remove(1));
+        assert!(!hset7.remove(1));
+        assert_eq!(hset7.len(), 1);
+        let (hset8, dropped8) = HashSet::from_vec_counting(&[1, 1, 2, 3, 3]);
+        assert_eq!(hset8.len(), 3);
+        assert_eq!(dropped8, 2);
+        assert_eq!(HashSet::<i32>::from_vec_counting(&[]).1, 0);
     }
 
     #[test]
@@ -741,6 +1545,28 @@ mod tests {
         assert!(!llist3.is_circular());
         println!("Reversed: {:?}", llist3.reverse());
         println!("Path: {:?}", llist3.path_of(1, 3));
+        let mut list9: LinkedList<i8> = LinkedList::from_vec(&vec![0, 1, 2, 3, 4]);
+        assert!(list9.swap(0, 3));
+        assert_eq!(list9.get(0), Some(&3));
+        assert_eq!(list9.get(3), Some(&0));
+        assert_eq!(list9.to_vec(), vec![kv!(0, 3), kv!(1, 1), kv!(2, 2), kv!(3, 0), kv!(4, 4)]);
+        assert!(list9.is_neighbor(1, 0) && list9.is_neighbor(2, 3) && list9.is_neighbor(3, 4));
+        assert_eq!(list9.edges(), 4);
+        assert!(!list9.swap(0, 5));
+        let mut llist4: LinkedList<i8> = LinkedList::from_vec(&vec![10, 20, 30]);
+        let rev4: LinkedList<i8> = llist4.reversed();
+        assert_eq!(rev4.clone().into_iter().collect::<Vec<(usize, i8)>>(),
+            vec![(0, 30), (1, 20), (2, 10)]);
+        assert_eq!(llist4.to_vec(), vec![kv!(0, 10), kv!(1, 20), kv!(2, 30)]);
+        llist4.reverse_in_place();
+        assert_eq!(llist4, rev4);
+        assert_eq!(llist4.clone().into_iter().collect::<Vec<(usize, i8)>>(),
+            vec![(0, 30), (1, 20), (2, 10)]);
+        llist4.reverse_in_place();
+        assert_eq!(llist4, LinkedList::from_vec(&vec![10, 20, 30]));
+        let mut llist5: LinkedList<i8> = LinkedList::circular_from_vec(&vec![1, 2, 3]);
+        llist5.reverse_in_place();
+        assert_eq!(llist5.reversed(), LinkedList::circular_from_vec(&vec![1, 2, 3]));
     }
 
     #[test]
@@ -818,6 +1644,52 @@ mod tests {
         let mut list4: List<i8> = List::from_vec(&list1.clone().to_vec());
         assert_eq!(list1, list4);
         println!("Reversed: {:?}", list4.reverse());
+        assert_eq!(List::from_vec(&vec![0, 1, 2]).to_string(), "[0, 1, 2]");
+        assert_eq!(List::<i8>::new().to_string(), "[]");
+        let mut list5: List<i8> = (0..5).collect();
+        assert_eq!(list5.to_vec(), vec![0, 1, 2, 3, 4]);
+        list5.extend(5..7);
+        assert_eq!(list5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        let mut list6: List<i8> = List::from_vec(&vec![1, 3, 5, 7]);
+        assert_eq!(list6.insert_sorted(4), 2);
+        assert_eq!(list6.to_vec(), vec![1, 3, 4, 5, 7]);
+        assert_eq!(list6.insert_sorted(0), 0);
+        assert_eq!(list6.insert_sorted(9), 6);
+        assert_eq!(list6.insert_sorted(4), 4);
+        let mut lists: Vec<List<i8>> = vec![List::from_vec(&vec![2, 1]),
+                                            List::from_vec(&vec![1, 3]),
+                                            List::from_vec(&vec![1, 2, 3]),
+                                            List::from_vec(&vec![1, 2])];
+        lists.sort();
+        assert_eq!(lists, vec![List::from_vec(&vec![1, 2]), List::from_vec(&vec![1, 2, 3]),
+                               List::from_vec(&vec![1, 3]), List::from_vec(&vec![2, 1])]);
+        let nan_list: List<f32> = List::from_vec(&vec![f32::NAN]);
+        assert!(List::from_vec(&vec![1.0]).partial_cmp(&nan_list).is_none());
+        let list7: List<i8> = List::from_vec(&vec![1, 2, 3, 4]);
+        assert!(list7.contains_subslice(&[2, 3]));
+        assert!(!list7.contains_subslice(&[2, 4]));
+        assert!(list7.contains_subslice(&[]));
+        let list8: List<i8> = List::from_vec(&vec![3, 1, 2, 1, 4]);
+        let list9: List<i8> = List::from_vec(&vec![5, 4, 1, 5, 6]);
+        assert_eq!(list8.union(&list9).to_vec(), vec![3, 1, 2, 4, 5, 6]);
+        assert_eq!(list8.intersection(&list9).to_vec(), vec![1, 4]);
+        assert_eq!(list9.intersection(&list8).to_vec(), vec![4, 1]);
+        assert!(list8.intersection(&List::new()).is_empty());
+        let list10: List<i8> = (0..5).collect();
+        assert_eq!(list10.fold(0, |acc, x| acc + x), 10);
+        assert_eq!(list10.fold(String::new(), |acc, x| acc + &x.to_string()), "01234");
+        assert_eq!(list10.to_vec(), vec![0, 1, 2, 3, 4]);
+        let list11: List<i8> = List::from_vec(&vec![0, 1, 2, 3]);
+        assert_eq!(list11.iter_rev().copied().collect::<Vec<i8>>(), vec![3, 2, 1, 0]);
+        assert_eq!(list11.to_vec(), vec![0, 1, 2, 3]);
+        let list12: List<i32> = List::from_vec(&vec![1, 2, 3, 4, 5]);
+        assert_eq!(list12.find(|x| *x > 3), Some((3, &4)));
+        assert_eq!(list12.rfind(|x| *x < 3), Some((1, &2)));
+        assert_eq!(list12.find(|x| *x > 5), None);
+        assert_eq!(list12.rfind(|x| *x > 5), None);
+        let mut list13: List<i32> = List::from_vec(&vec![1, 2, 3, 4, 5]);
+        assert_eq!(list13.swap_remove(0), 1);
+        assert_eq!(list13.to_vec(), vec![5, 2, 3, 4]);
     }
 
     #[test]
@@ -831,6 +1703,10 @@ mod tests {
         assert!(map1.capacity() > 0);
         assert!(map1.contains(&kv!(1, 1.5)));
         assert!(map1.contains_all(&vec![kv!(0, 3.2), kv!(1, 1.5), kv!(2, 6.7)]));
+        assert!(map1.contains_key(&4));
+        assert!(!map1.contains_key(&5));
+        assert!(map1.contains_value(&6.7));
+        assert!(!map1.contains_value(&9.9));
         assert_eq!(map1, map1.clone());
         assert_eq!(map1.len(), 5);
         for i in map1.clone().into_iter() {
@@ -850,6 +1726,34 @@ mod tests {
         assert!(map1.is_sorted_rev());
         let map2: Map<i32, f32> = Map::from_vec(&map1.to_vec());
         assert_eq!(map1, map2);
+        let mut map5: Map<i32, i8> = (0..3).map(|i| kv!(i, i as i8)).collect();
+        assert_eq!(map5.to_vec(), vec![kv!(0, 0), kv!(1, 1), kv!(2, 2)]);
+        map5.extend(vec![kv!(2, 5), kv!(3, 3)]);
+        assert_eq!(map5.to_vec(), vec![kv!(0, 0), kv!(1, 1), kv!(2, 2), kv!(3, 3)]);
+        let mut pairs1: Vec<KeyValue<i32, i8>> = vec![kv!(3, 1), kv!(1, 3), kv!(2, 2), kv!(1, 1)];
+        pairs1.sort_by(|a, b| a.cmp_by_value(b));
+        assert_eq!(pairs1.iter().map(|p| p.value).collect::<Vec<i8>>(), vec![1, 1, 2, 3]);
+        assert_eq!(pairs1[0].key, 3);
+        pairs1.sort_by(|a, b| a.cmp_by_key(b));
+        assert_eq!(pairs1.iter().map(|p| p.key).collect::<Vec<i32>>(), vec![1, 1, 2, 3]);
+        assert_eq!(pairs1[0], kv!(1, 1));
+        pairs1.sort();
+        assert_eq!(pairs1, vec![kv!(1, 1), kv!(1, 3), kv!(2, 2), kv!(3, 1)]);
+        assert!(kv!(1, 1) < kv!(1, 2));
+        let mut map6: Map<&str, i32> = Map::new();
+        map6.insert(kv!("the", 7));
+        map6.insert(kv!("a", 3));
+        map6.insert(kv!("of", 5));
+        map6.insert(kv!("and", 1));
+        assert!(!map6.is_sorted_by_value());
+        map6.sort_by_value();
+        assert!(map6.is_sorted_by_value());
+        let ranked: Vec<i32> = map6.clone().into_iter().map(|p| p.value).collect();
+        assert_eq!(ranked, vec![1, 3, 5, 7]);
+        assert_eq!(map6.to_vec()[0].key, "and");
+        map6.sort_by_value_rev();
+        assert_eq!(map6.to_vec()[0], kv!("the", 7));
+        assert!(!map6.is_sorted_by_value());
     }
 
     #[test]
@@ -881,6 +1785,131 @@ mod tests {
         let q3: Queue<i8> = Queue::with_capacity(10);
         assert_eq!(q3.capacity(), 10);
         println!("Reversed: {:?}", q2.reverse());
+        let mut queue5: Queue<i8> = (0..5).collect();
+        assert_eq!(queue5.to_vec(), vec![0, 1, 2, 3, 4]);
+        queue5.extend(5..7);
+        assert_eq!(queue5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(queue5.dequeue(), Some(0));
+        let mut queue6: Queue<i8> = Queue::new();
+        queue6.enqueue(1);
+        queue6.enqueue(2);
+        *queue6.peek_mut().expect("Queue peek mut failed.") = 5;
+        assert_eq!(queue6.dequeue(), Some(5));
+        assert_eq!(queue6.dequeue(), Some(2));
+        assert_eq!(queue6.peek_mut(), None);
+        let mut queue7: Queue<i8> = (0..12).collect();
+        let orig7: Vec<i8> = queue7.to_vec();
+        assert_eq!(queue7.reverse().to_vec(), (0..12).rev().collect::<Vec<i8>>());
+        assert_eq!(queue7.to_vec(), orig7);
+        queue7.reverse_in_place();
+        assert_eq!(queue7.peek(), Some(&11));
+        queue7.reverse_in_place();
+        assert_eq!(queue7.to_vec(), orig7);
+        let queue8: Queue<i8> = Queue::from(vec![1, 2, 3]);
+        assert_eq!(queue8.peek(), Some(&1));
+        assert_eq!(Vec::from(queue8), vec![1, 2, 3]);
+        let vec8: Vec<i8> = Queue::from(vec![4, 5]).into();
+        assert_eq!(vec8, vec![4, 5]);
+        let mut queue12: Queue<i8> = Queue::from(vec![1, 2, 3]);
+        assert!(!queue12.is_full());
+        assert!(queue12.enqueue(4));
+        assert_eq!(Vec::from(queue12), vec![1, 2, 3, 4]);
+        let mut queue9: Queue<i8> = Queue::new();
+        queue9.enqueue_all(vec![1, 2, 3, 4, 5]);
+        assert_eq!(queue9.len(), 5);
+        assert_eq!(queue9.dequeue_n(3), vec![1, 2, 3]);
+        assert_eq!(queue9.len(), 2);
+        assert_eq!(queue9.peek(), Some(&4));
+        assert_eq!(queue9.dequeue_n(5), vec![4, 5]);
+        assert!(queue9.is_empty());
+        let mut queue10: Queue<i32> = Queue::new();
+        queue10.enqueue_all((0..1000).collect());
+        queue10.dequeue_n(995);
+        let cap10: usize = queue10.capacity();
+        queue10.shrink_to_fit();
+        assert!(queue10.capacity() < cap10);
+        assert!(queue10.capacity() >= queue10.len());
+        assert_eq!(queue10.peek(), Some(&995));
+        let mut queue11: Queue<i32> = Queue::from(vec![1, 2, 3]);
+        let cap11: usize = queue11.capacity();
+        assert_eq!(queue11.drain(), vec![1, 2, 3]);
+        assert!(queue11.is_empty());
+        assert_eq!(queue11.capacity(), cap11);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn rayon_test() {
+        use rayon::prelude::*;
+        let vec1: Vector<i64> = (0..100000).collect();
+        let par_sum: i64 = vec1.par_iter().sum();
+        assert_eq!(par_sum, vec1.to_vec().iter().sum());
+        let arr1: Array<i64, 5> = Array::from_vec(&vec![1, 2, 3, 4, 5]);
+        assert_eq!(arr1.par_iter().sum::<i64>(), 15);
+        let mut grid1: Grid<i64> = Grid::new();
+        grid1.resize(10, 10);
+        grid1[(3, 4)] = 7;
+        assert_eq!(grid1.par_iter().sum::<i64>(), 7);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let arr1: Array<i8, 5> = Array::from_vec(&vec![1, 2, 3, 4, 5]);
+        let json: String = serde_json::to_string(&arr1).unwrap();
+        assert_eq!(serde_json::from_str::<Array<i8, 5>>(&json).unwrap(), arr1);
+        assert!(serde_json::from_str::<Array<i8, 4>>(&json).is_err());
+        let vec1: Vector<i8> = Vector::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&vec1).unwrap();
+        assert_eq!(serde_json::from_str::<Vector<i8>>(&json).unwrap(), vec1);
+        let list1: List<i8> = List::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&list1).unwrap();
+        assert_eq!(serde_json::from_str::<List<i8>>(&json).unwrap(), list1);
+        let queue1: Queue<i8> = Queue::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&queue1).unwrap();
+        assert_eq!(serde_json::from_str::<Queue<i8>>(&json).unwrap(), queue1);
+        let deque1: Deque<i8> = Deque::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&deque1).unwrap();
+        assert_eq!(serde_json::from_str::<Deque<i8>>(&json).unwrap(), deque1);
+        let stack1: Stack<i8> = Stack::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&stack1).unwrap();
+        assert_eq!(serde_json::from_str::<Stack<i8>>(&json).unwrap(), stack1);
+        assert!(serde_json::from_str::<Stack<i8>>(r#"{"capacity":2,"deq":[1,2,3]}"#).is_err());
+        let mut stack2: Stack<i8> = serde_json::from_str(r#"{"deq":[1,2,3]}"#).unwrap();
+        assert!(stack2.push(4));
+        let set1: Set<i8> = Set::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&set1).unwrap();
+        assert_eq!(serde_json::from_str::<Set<i8>>(&json).unwrap(), set1);
+        let hset1: HashSet<i8> = HashSet::from_vec(&vec![1, 2, 3]);
+        let json: String = serde_json::to_string(&hset1).unwrap();
+        assert_eq!(serde_json::from_str::<HashSet<i8>>(&json).unwrap(), hset1);
+        let map1: Map<i32, i8> = Map::from_vec(&vec![kv!(1, 1), kv!(2, 2), kv!(3, 3)]);
+        let json: String = serde_json::to_string(&map1).unwrap();
+        assert_eq!(serde_json::from_str::<Map<i32, i8>>(&json).unwrap(), map1);
+        let hmap1: HashMap<i32, i8> = HashMap::from_vec(&vec![kv!(1, 1), kv!(2, 2), kv!(3, 3)]);
+        let json: String = serde_json::to_string(&hmap1).unwrap();
+        assert_eq!(serde_json::from_str::<HashMap<i32, i8>>(&json).unwrap(), hmap1);
+        let dict1: Dictionary<i8> = Dictionary::from_vec(&vec![dkv!("One", 1), dkv!("Two", 2)]);
+        let json: String = serde_json::to_string(&dict1).unwrap();
+        assert_eq!(serde_json::from_str::<Dictionary<i8>>(&json).unwrap(), dict1);
+        let grid1: Grid<i8> = Grid::from_vec(2, 3, &vec![1, 2, 3, 4, 5, 6]);
+        let json: String = serde_json::to_string(&grid1).unwrap();
+        assert_eq!(serde_json::from_str::<Grid<i8>>(&json).unwrap(), grid1);
+        assert!(serde_json::from_str::<Grid<i8>>(r#"{"arr":[1,2,3],"cols":3,"rows":2}"#).is_err());
+        let mut tree1: Tree<i32, i8> = Tree::new();
+        tree1.insert_at(None, kv!(400, 1));
+        tree1.insert_at(Some(400), kv!(100, 2));
+        tree1.insert_at(Some(100), kv!(200, 3));
+        let json: String = serde_json::to_string(&tree1).unwrap();
+        assert_eq!(serde_json::from_str::<Tree<i32, i8>>(&json).unwrap(), tree1);
+        assert!(serde_json::from_str::<Tree<i32, i8>>(&json).unwrap().is_valid_tree());
+        assert!(serde_json::from_str::<Tree<i32, i8>>(&json.replace("[400,", "[null,")).is_err());
+        assert!(serde_json::from_str::<Tree<i32, i8>>(&json.replace("[100]", "[100,400]"))
+            .is_err());
+        let json: String = serde_json::to_string(&kv!(1, 2)).unwrap();
+        assert_eq!(serde_json::from_str::<KeyValue<i32, i8>>(&json).unwrap(), kv!(1, 2));
+        let json: String = serde_json::to_string(&Pos::at(1, 2)).unwrap();
+        assert_eq!(serde_json::from_str::<Pos>(&json).unwrap(), Pos::at(1, 2));
     }
 
     #[test]
@@ -939,6 +1968,62 @@ mod tests {
         assert!(seta.is_finite());
         setb.complement();
         assert!(!setb.is_complement());
+        let mut set5: Set<i8> = (0..5).collect();
+        assert_eq!(set5.to_vec(), vec![0, 1, 2, 3, 4]);
+        set5.extend(3..7);
+        assert_eq!(set5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        let set6: Set<i32> = (0..5).collect();
+        assert_eq!(set6.len(), 5);
+        let mut sum: i32 = 0;
+        for i in set6.iter() {
+            sum += *i;
+        }
+        assert_eq!(sum, 10);
+        assert_eq!(set6.iter().count(), 5);
+        assert!(set6.iter().all(|i| set6.contains(i)));
+        let setd: Set<i8> = Set::complement_of(&seta);
+        assert!(!setd.contains(&1));
+        assert!(!setd.contains(&3));
+        assert!(setd.contains(&0));
+        assert!(setd.contains(&4));
+        assert!(setd.contains_all(&vec![0, 4, 5]));
+        assert!(!setd.contains_all(&vec![0, 2]));
+        assert_eq!(setd.cardinality(), None);
+        assert_eq!(Set::<i8>::new_inf().cardinality(), None);
+        assert_eq!(seta.cardinality(), Some(3));
+        assert_eq!(Set::<i8>::new().cardinality(), Some(0));
+        let set7: Set<i8> = Set::from_vec(&vec![3, 4, 5]);
+        let mut set8: Set<i8> = Set::from_vec(&vec![1, 2, 3, 4]);
+        set8.unite(&set7);
+        assert_eq!(set8.cardinality(), Some(5));
+        assert!(set8.contains_all(&vec![1, 2, 3, 4, 5]));
+        let mut set9: Set<i8> = Set::from_vec(&vec![1, 2, 3, 4]);
+        set9.intersect(&set7);
+        assert_eq!(set9.to_vec(), vec![3, 4]);
+        let mut set10: Set<i8> = Set::from_vec(&vec![1, 2, 3, 4]);
+        set10.subtract(&set7);
+        assert_eq!(set10.to_vec(), vec![1, 2]);
+        assert!(!set10.contains(&3));
+        let mut set11: Set<i8> = Set::not_from_vec(&vec![1]);
+        set11.intersect(&Set::from_vec(&vec![1, 2]));
+        assert!(set11.is_finite());
+        assert_eq!(set11.to_vec(), vec![2]);
+        let mut set12: Set<i8> = Set::not_from_vec(&vec![1, 2]);
+        set12.unite(&Set::from_vec(&vec![1]));
+        assert!(set12.contains(&1) && !set12.contains(&2) && set12.contains(&9));
+        set12.subtract(&Set::from_vec(&vec![9]));
+        assert!(!set12.contains(&9) && set12.contains(&8));
+        let mut set13: Set<i8> = Set::from_vec(&vec![1, 2, 3]);
+        assert_eq!(set13.take(&2), Some(2));
+        assert_eq!(set13.take(&2), None);
+        assert!(!set13.contains(&2));
+        assert!(set13.remove(1));
+        assert!(!set13.remove(1));
+        assert_eq!(set13.to_vec(), vec![3]);
+        let (set14, dropped14) = Set::from_vec_counting(&[1, 1, 2, 3, 3]);
+        assert_eq!(set14.len(), 3);
+        assert_eq!(dropped14, 2);
+        assert!(set14.contains_all(&vec![1, 2, 3]));
     }
 
     #[test]
@@ -965,7 +2050,7 @@ mod tests {
         assert!(stack2 == stack2);
         assert!(stack1.contains(&2));
         assert!(stack2.contains_all(&stack2.clone().to_vec()));
-        assert_eq!(stack1.pop(), Some(1));
+        assert_eq!(stack1.pop(), Some(3));
         assert_eq!(stack1.peek_top(), Some(&2));
         let stack3: Stack<i8> = Stack::from_vec(&stack1.clone().to_vec());
         assert_eq!(stack3, stack1);
@@ -975,6 +2060,56 @@ mod tests {
         }
         println!("{:?}", stack4);
         assert_eq!(stack4.len(), stack4.capacity());
+        let mut stack5: Stack<i8> = (0..5).collect();
+        assert_eq!(stack5.to_vec(), vec![0, 1, 2, 3, 4]);
+        stack5.extend(5..7);
+        assert_eq!(stack5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        let mut stack6: Stack<i8> = Stack::new();
+        stack6.push(1);
+        stack6.push(2);
+        stack6.push(3);
+        assert_eq!(stack6.iter().collect::<Vec<&i8>>(), vec![&3, &2, &1]);
+        assert_eq!(stack6.len(), 3);
+        assert_eq!(stack6.to_vec(), vec![1, 2, 3]);
+        assert_eq!(stack6.pop(), Some(3));
+        assert_eq!(stack6.iter().next(), stack6.peek_top());
+        let stack7: Stack<i8> = vec![1, 2, 3].into();
+        assert_eq!(stack7.peek_top(), Some(&3));
+        assert_eq!(Vec::from(stack7), vec![1, 2, 3]);
+        let mut stack11: Stack<i8> = Stack::from(vec![1, 2, 3]);
+        assert!(!stack11.is_full());
+        assert!(stack11.push(4));
+        assert_eq!(stack11.pop(), Some(4));
+        let mut stack8: Stack<i8> = Stack::with_capacity(4);
+        let cap8: usize = stack8.capacity();
+        for i in 0..cap8 {
+            assert!(stack8.push(i as i8));
+        }
+        assert!(!stack8.push(0));
+        stack8.set_growable(true);
+        for i in 0..cap8 {
+            assert!(stack8.push(i as i8));
+        }
+        assert_eq!(stack8.len(), cap8 * 2);
+        assert!(stack8.capacity() > cap8);
+        let mut stack9: Stack<i32> = Stack::new();
+        stack9.set_growable(true);
+        for i in 0..1000 {
+            stack9.push(i);
+        }
+        for _ in 0..995 {
+            stack9.pop();
+        }
+        let cap9: usize = stack9.capacity();
+        stack9.shrink_to_fit();
+        assert!(stack9.capacity() < cap9);
+        assert!(stack9.capacity() >= stack9.len());
+        assert_eq!(stack9.peek_top(), Some(&4));
+        let mut stack10: Stack<i32> = Stack::from(vec![1, 2, 3]);
+        let cap10: usize = stack10.capacity();
+        assert_eq!(stack10.drain(), vec![3, 2, 1]);
+        assert!(stack10.is_empty());
+        assert_eq!(stack10.capacity(), cap10);
     }
 
     #[test]
@@ -1007,6 +2142,22 @@ mod tests {
         assert!(!slist1.contains_all(&vec![4, 5, 6]));
         slist1.set(1, &List::from_vec(&vec![4, 5, 6]));
         assert!(slist1.contains_all(&vec![4, 5, 6]));
+        let flat: List<i8> = slist1.flatten();
+        assert_eq!(flat.len(), slist1[0].len() + slist1[1].len());
+        assert_eq!(flat.to_vec(), vec![1, 2, 3, 4, 5, 6]);
+        slist1.set(0, &List::from_vec(&vec![3, 1, 2]));
+        slist1.set(1, &List::from_vec(&vec![6, 4, 5]));
+        slist1.map_each(|l| {
+            let mut sorted: List<i8> = l.clone();
+            sorted.sort();
+            sorted
+        });
+        for i in 0..slist1.len() {
+            assert!(slist1[i].is_sorted());
+        }
+        slist1.get_mut(0).unwrap().append(4);
+        assert_eq!(slist1[0].to_vec(), vec![1, 2, 3, 4]);
+        assert!(slist1.get_mut(slist1.len()).is_none());
     }
 
     #[test]
@@ -1096,8 +2247,51 @@ mod tests {
         t1[(3, 3)] = Cell::new_data(CellType::String(str!("Set")));
         assert_eq!(t1[(3, 3)], Cell::new_data(CellType::String(str!("Set"))));
         println!("{:?}", t1);
+        let t2: Table = t1.transpose();
+        println!("{}", t2);
+        assert_eq!(t2.rows(), t1.columns());
+        assert_eq!(t2.columns(), t1.rows());
+        assert_eq!(t2.get(Pos::at(0, 1)), t1.get(Pos::at(1, 0)));
+        assert_eq!(t2.get(Pos::at(0, 3)), Some(&Cell::new_data(CellType::String(str!("New 4")))));
+        assert!(t2.transpose() == t1);
+        let mut t3: Table = Table::from_vec(1, 2, &vec!(CellType::Integer(1), CellType::Empty));
+        t3.set_col_headers(vec!(str!("A"), str!("B")));
+        t3.set_row_headers(vec!(str!("R")));
+        let t4: Table = t3.transpose();
+        println!("{}", t4);
+        assert_eq!(t4.rows(), 2);
+        assert_eq!(t4.get(Pos::at(1, 0)), Some(&Cell::new()));
+        let mut t5: Table = Table::from_vec(4, 2, &vec!(
+            CellType::String(str!("A")), CellType::Integer(3),
+            CellType::String(str!("B")), CellType::Integer(8),
+            CellType::String(str!("C")), CellType::Integer(1),
+            CellType::String(str!("D")), CellType::Integer(12)));
+        t5.set_row_headers(vec!(str!("Row 1"), str!("Row 2"), str!("Row 3"), str!("Row 4")));
+        t5.filter_rows(1, |c| match c.get() {
+            CellType::Integer(n) => *n > 5,
+            _ => false,
+        });
+        println!("{}", t5);
+        assert_eq!(t5.rows(), 2);
+        assert_eq!(t5.len(), 4);
+        assert_eq!(t5.get_col(0), Some(vec!(
+            Cell::new_data(CellType::String(str!("B"))),
+            Cell::new_data(CellType::String(str!("D"))))));
         t1.clear();
         assert!(t1.is_empty());
+        let mut t6: Table = Table::from_vec(2, 2, &vec!(
+            CellType::Integer(4), CellType::Empty,
+            CellType::String(str!("A")), CellType::Empty));
+        let replaced: usize = t6.replace_cells(|c| match c.get() {
+            CellType::Empty => Some(Cell::new_data(CellType::Integer(0))),
+            _ => None,
+        });
+        assert_eq!(replaced, 2);
+        assert_eq!(t6.get_col(1), Some(vec!(
+            Cell::new_data(CellType::Integer(0)),
+            Cell::new_data(CellType::Integer(0)))));
+        assert_eq!(t6.get(Pos::at(1, 0)), Some(&Cell::new_data(CellType::String(str!("A")))));
+        assert_eq!(t6.replace_cells(|_| None), 0);
     }
 
     #[test]
@@ -1225,6 +2419,228 @@ mod tests {
         let tree2: Tree<i32, i8> = Tree::from_vec(&tree1.clone().to_vec());
         assert_eq!(tree1, tree2);
         println!("Path: {:?}", tree1.path_of(400, 10));
+        let tree3: Tree<i32, String> = tree1.map_values(|v| v.to_string());
+        assert_eq!(tree3.len(), tree1.len());
+        assert_eq!(tree3[510], str!("10"));
+        assert_eq!(tree3.depth_of(&510), tree1.depth_of(&510));
+        let mut trav1 = tree1.clone().into_trav();
+        let mut trav3 = tree3.clone().into_trav();
+        trav1.preorder();
+        trav3.preorder();
+        while trav1.has_next() {
+            assert_eq!(trav3.next(), trav1.next().map(|v| v.to_string()));
+        }
+        assert!(!trav3.has_next());
+        assert_eq!(tree1.internal_count(), 3);
+        assert_eq!(Tree::<i32, i8>::new().internal_count(), 0);
+        let modes: Vec<TraversalMode> = vec![TraversalMode::Inorder, TraversalMode::LevelOrder,
+                                             TraversalMode::Postorder, TraversalMode::Preorder];
+        for mode in modes.iter() {
+            let list: Vec<KeyValue<usize, KeyValue<i32, i8>>> = tree1.to_list(*mode).to_vec();
+            assert_eq!(list.len(), tree1.len());
+            let mut trav = tree1.clone().into_trav();
+            match mode {
+                TraversalMode::Inorder => trav.inorder(),
+                TraversalMode::LevelOrder => trav.level_order(),
+                TraversalMode::Postorder => trav.postorder(),
+                TraversalMode::Preorder => trav.preorder(),
+            }
+            for item in list.iter() {
+                let pair: KeyValue<i32, i8> = item.value.clone();
+                assert_eq!(pair.value, trav.next().expect("Failed to traverse tree"));
+                assert_eq!(tree1.get(pair.key), Some(&pair.value));
+            }
+            assert!(!trav.has_next());
+        }
+        let path: Vec<KeyValue<usize, KeyValue<usize, i8>>> = tree1.path_between(10, 510)
+            .expect("Failed to find path between tree nodes").to_vec();
+        assert_eq!(path.iter().map(|kv| kv.value.value).collect::<Vec<i8>>(), vec![7, 2, 1, 5, 10]);
+        assert_eq!(path.iter().map(|kv| kv.value.key).collect::<Vec<usize>>(), vec![0, 1, 2, 3, 4]);
+        assert_eq!(tree1.path_between(10, 100).expect("Failed to find path").len(), 2);
+        assert_eq!(tree1.path_between(10, 10).expect("Failed to find path").len(), 1);
+        assert!(tree1.path_between(10, 1000).is_none());
+        // contains looks nodes up by key without cloning the tree, so many calls on a large tree
+        // stay fast.
+        let mut tree4: Tree<i32, i32> = Tree::new_root(kv!(1, 1));
+        for i in 2..10000 {
+            tree4.insert_at(Some(i / 2), kv!(i, i));
+        }
+        for i in 1..10000 {
+            assert!(tree4.contains(&kv!(i, i)));
+            assert!(!tree4.contains(&kv!(i, i + 1)));
+        }
+        assert!(!tree4.contains(&kv!(10000, 10000)));
+        assert_eq!(tree4.to_vec().len(), 9999);
+        let mut trav2 = tree1.trav();
+        let pairs: Vec<KeyValue<i32, i8>> = tree1.to_vec();
+        for pair in pairs.iter() {
+            assert_eq!(trav2.next(), Some(pair.value));
+        }
+        assert!(!trav2.has_next());
+        let mut tree11: Tree<i32, i32> = Tree::new_root(kv!(1, 1));
+        tree11.insert_at(Some(1), kv!(2, 2));
+        tree11.insert_at(Some(1), kv!(3, 3));
+        let mut trav5 = tree11.trav();
+        tree11.clear();
+        assert!(tree11.is_empty());
+        let mut order: Vec<i32> = Vec::new();
+        trav5.postorder();
+        while trav5.has_next() {
+            order.push(trav5.next().expect("Failed to traverse tree"));
+        }
+        assert_eq!(order, vec![2, 3, 1]);
+        trav5.level_order();
+        assert_eq!(trav5.next(), Some(1));
+        let tree5: &Tree<i32, i8> = &tree1;
+        let sub2: Tree<i32, i8> = tree5.subtree(100);
+        assert_eq!(sub2.root_node(), Some(&2));
+        assert!(sub2.exists(10) && sub2.exists(20));
+        assert!(!sub2.exists(400) && !sub2.exists(500));
+        let mut tree6: Tree<i32, i32> = Tree::new_root(kv!(0, 0));
+        for i in 1..500 {
+            tree6.insert_at(Some(i - 1), kv!(i, i));
+        }
+        let mut trav4 = tree6.trav();
+        for i in 0..500 {
+            assert_eq!(trav4.next(), Some(i));
+        }
+        assert!(!trav4.has_next());
+        trav4.postorder();
+        assert_eq!(trav4.next(), Some(499));
+        trav4.preorder();
+        assert_eq!(trav4.next(), Some(0));
+        assert_eq!(tree6.to_vec().len(), 500);
+        let levels: Vec<Vec<i8>> = tree1.levels();
+        assert_eq!(levels[0], vec![*tree1.root_node().expect("Failed to get root node")]);
+        assert_eq!(levels.len() as isize, tree1.height() + 1);
+        assert_eq!(levels.iter().map(|l| l.len()).sum::<usize>(), tree1.len());
+        assert!(Tree::<i32, i8>::new().levels().is_empty());
+        let ascii: String = tree1.to_ascii();
+        assert_eq!(ascii.lines().count(), tree1.len());
+        assert!(ascii.starts_with("400: 1\n"));
+        assert_eq!(ascii.lines().filter(|l| !l.starts_with(['├', '└', '│', ' '])).count(), 1);
+        assert!(Tree::<i32, i8>::new().to_ascii().is_empty());
+        let graph1: UUGraph<i8> = tree1.to_graph();
+        assert_eq!(graph1.capacity(), tree1.len());
+        assert_eq!(graph1.edges(), tree1.edges());
+        assert!(graph1.is_connected());
+        assert!(graph1.is_neighbor(0, 1) && graph1.is_neighbor(1, 0));
+        assert_eq!(graph1.get(0), tree1.root_node());
+        let mut tree7: Tree<i32, i8> = Tree::new_root(kv!(0, 0));
+        tree7.insert_at(Some(0), kv!(1, 1));
+        assert_eq!(tree7.insert_children(1, &[kv!(2, 2), kv!(3, 3), kv!(4, 4), kv!(5, 5),
+            kv!(6, 6)]), 5);
+        assert_eq!(tree7.child_nodes(&1).len(), 5);
+        assert_eq!(tree7.len(), 7);
+        assert_eq!(tree7.insert_children(0, &[kv!(3, 3), kv!(7, 7)]), 1);
+        assert_eq!(tree7.child_nodes(&0).len(), 2);
+        assert_eq!(tree7.insert_children(8, &[kv!(9, 9)]), 0);
+        assert!(!tree7.exists(9));
+        let mut tree8: Tree<i32, i8> = tree1.clone();
+        let detached: Tree<i32, i8> = tree8.detach_subtree(100)
+            .expect("Failed to detach subtree from tree.");
+        assert_eq!(detached.len(), 3);
+        assert!(detached.exists(100) && detached.exists(10) && detached.exists(20));
+        assert_eq!(detached.root_node(), Some(&2));
+        assert_eq!(detached.parent_node(&100), None);
+        assert_eq!(detached.child_nodes(&100).len(), 2);
+        assert_eq!(tree8.len(), tree1.len() - 3);
+        assert!(!tree8.exists(100) && !tree8.exists(10) && !tree8.exists(20));
+        assert!(tree8.exists(300) && tree8.exists(510));
+        assert_eq!(tree8.child_nodes(&400).len(), tree1.child_nodes(&400).len() - 1);
+        assert!(tree8.detach_subtree(100).is_none());
+        let mut tree15: Tree<i32, i8> = Tree::new_root(kv!(0, 0));
+        tree15.insert_at(Some(0), kv!(1, 1));
+        for i in 0..18 {
+            tree15.insert_at(Some(1), kv!(100 + i, i as i8));
+        }
+        let wide: Tree<i32, i8> = tree15.detach_subtree(1)
+            .expect("Failed to detach subtree from tree.");
+        assert_eq!(wide.len(), 19);
+        assert_eq!(tree15.len(), 1);
+        assert!(tree15.exists(0) && !tree15.exists(1) && !tree15.exists(117));
+        assert!(tree15.is_valid_tree());
+        assert!(tree1.is_valid_tree());
+        assert!(tree8.is_valid_tree());
+        assert!(detached.is_valid_tree());
+        assert!(tree7.is_valid_tree());
+        assert!(Tree::<i32, i8>::new().is_valid_tree());
+        let mut tree13: Tree<i32, i8> = tree1.clone();
+        for pair in tree1.to_vec() {
+            tree13[pair.key] = 0;
+        }
+        let text: String = tree13.serialize();
+        assert_eq!(text, "400(100(10)(20))(300)(500(510))(600)");
+        assert_eq!(Tree::<i32, i8>::deserialize(&text), Ok(tree13.clone()));
+        let tree9: Tree<i32, i8> = Tree::deserialize("400(100(10)(20))(200(110))")
+            .expect("Failed to deserialize tree");
+        assert_eq!(tree9.len(), 6);
+        assert_eq!(tree9[110], 0);
+        assert_eq!(tree9.child_nodes(&100).len(), 2);
+        assert_eq!(tree9.serialize(), "400(100(10)(20))(200(110))");
+        assert!(Tree::<i32, i8>::deserialize("").expect("Failed to deserialize tree").is_empty());
+        assert_eq!(Tree::<i32, i8>::deserialize("400(x)"), Err(ParseError::InvalidKey(4)));
+        assert_eq!(Tree::<i32, i8>::deserialize("400(100"), Err(ParseError::UnexpectedEnd));
+        assert_eq!(Tree::<i32, i8>::deserialize("400)"), Err(ParseError::UnexpectedChar(3)));
+        assert_eq!(Tree::<i32, i8>::deserialize("400()"), Err(ParseError::UnexpectedChar(4)));
+        assert_eq!(Tree::<i32, i8>::deserialize("1(2(3)x"), Err(ParseError::UnexpectedChar(6)));
+        assert_eq!(Tree::<i32, i8>::deserialize("1(2)x"), Err(ParseError::UnexpectedChar(4)));
+        assert_eq!(Tree::<i32, i8>::deserialize("1(1)"), Err(ParseError::DuplicateKey(2)));
+        let mut deep: String = String::new();
+        for i in 0..200000 {
+            deep.push_str(&format!("{}(", i));
+        }
+        deep.push_str("200000");
+        deep.push_str(&")".repeat(200000));
+        let tree14: Tree<i32, i8> = Tree::deserialize(&deep).expect("Failed to deserialize tree");
+        assert_eq!(tree14.len(), 200001);
+        assert_eq!(tree14.serialize(), deep);
+        let mut tree10: Tree<i32, i8> = tree9.clone();
+        tree10.freeze();
+        assert!(tree10.is_frozen());
+        for pair in tree9.to_vec() {
+            assert_eq!(tree10.depth_of(&pair.key), tree9.depth_of(&pair.key));
+        }
+        assert_eq!(tree10.depth_of(&999), -1);
+        assert_eq!(tree10.height(), tree9.height());
+        assert!(tree10.insert_at(Some(10), kv!(1, 11)));
+        assert!(!tree10.is_frozen());
+        assert_eq!(tree10.depth_of(&1), 3);
+        assert_eq!(tree10.height(), 3);
+        tree10.freeze();
+        assert_eq!(tree10.depth_of(&1), 3);
+        tree10.remove(100);
+        assert!(!tree10.is_frozen());
+        assert_eq!(tree10.depth_of(&1), -1);
+        tree10.freeze();
+        tree10.clear();
+        assert!(!tree10.is_frozen());
+        tree10.freeze();
+        assert_eq!(tree10.height(), -1);
+    }
+
+    #[test]
+    fn tree_deep_test() {
+        let mut tree: Tree<i32, i32> = Tree::new_root(kv!(0, 0));
+        for i in 1..10000 {
+            tree.insert_at(Some(i - 1), kv!(i, i));
+        }
+        let mut trav = tree.trav();
+        for i in 0..10000 {
+            assert_eq!(trav.next(), Some(i));
+        }
+        assert!(!trav.has_next());
+        trav.postorder();
+        assert_eq!(trav.next(), Some(9999));
+        assert_eq!(tree.to_vec().len(), 10000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot remove the vector element due to out-of-bounds index")]
+    fn vector_swap_remove_test() {
+        let mut vec1: Vector<i8> = Vector::from_vec(&vec![1, 2, 3]);
+        assert_eq!(vec1.swap_remove(2), 3);
+        vec1.swap_remove(2);
     }
 
     #[test]
@@ -1244,7 +2660,7 @@ mod tests {
         vec1.shrink();
         assert_eq!(vec1.len(), vec1.capacity());
         let len: usize = vec1.len();
-        vec1.extend(5, &0);
+        VectorCollection::extend(&mut vec1, 5, &0);
         assert_eq!(vec1.len(), len + 5);
         vec1.truncate(len);
         assert_eq!(vec1.len(), len);
@@ -1292,5 +2708,154 @@ mod tests {
         let vec4: Vector<i8> = Vector::with_length(10, &0);
         assert_eq!(vec4.len(), 10);
         println!("Reversed: {:?}", vec1.reverse());
+        let mut vec5: Vector<i8> = (0..5).collect();
+        assert_eq!(vec5.to_vec(), vec![0, 1, 2, 3, 4]);
+        Extend::extend(&mut vec5, vec![5, 6]);
+        assert_eq!(vec5.to_vec(), vec![0, 1, 2, 3, 4, 5, 6]);
+        let mut recs: Vector<(i32, i8)> = Vector::from_vec(&vec![(3, 1), (1, 2), (4, 3), (2, 4)]);
+        recs.sort();
+        assert_eq!(recs.binary_search_by_key(&3, |r| r.0), Ok(2));
+        assert_eq!(recs.binary_search_by_key(&0, |r| r.0), Err(0));
+        assert_eq!(recs.binary_search_by_key(&9, |r| r.0), Err(4));
+        let mut vec6: Vector<i8> = (0..10).collect();
+        vec6.splice(2..4, vec![20, 30, 40]);
+        assert_eq!(vec6.len(), 11);
+        assert_eq!(vec6.to_vec(), vec![0, 1, 20, 30, 40, 4, 5, 6, 7, 8, 9]);
+        vec6.splice(1..1, vec![10]);
+        assert_eq!(vec6.to_vec(), vec![0, 10, 1, 20, 30, 40, 4, 5, 6, 7, 8, 9]);
+        vec6.splice(3..6, Vec::new());
+        assert_eq!(vec6.to_vec(), vec![0, 10, 1, 4, 5, 6, 7, 8, 9]);
+        let mut vec7: Vector<i8> = Vector::from_vec(&vec![1, 2, 3]);
+        vec7.reserve(100);
+        assert!(vec7.capacity() >= 100);
+        vec7.shrink_to(50);
+        assert!(vec7.capacity() >= 50 && vec7.capacity() <= 103);
+        assert!(vec7.capacity() >= vec7.len());
+        vec7.shrink_to(0);
+        assert!(vec7.capacity() >= vec7.len());
+        let mut vec8: Vector<i8> = Vector::from_vec(&vec![1, 3, 5, 7]);
+        assert_eq!(vec8.insert_sorted(4), 2);
+        assert_eq!(vec8.to_vec(), vec![1, 3, 4, 5, 7]);
+        assert_eq!(Vector::<i8>::new().insert_sorted(1), 0);
+        assert!(Vector::from_vec(&vec![1, 2]) < Vector::from_vec(&vec![1, 3]));
+        let vec9: Vector<i8> = Vector::from_vec(&vec![4]);
+        assert_eq!(vec9.clone().max(Vector::from_vec(&vec![3, 9])), vec9);
+        let vec10: Vector<i8> = Vector::from_vec(&vec![1, 2, 3, 4]);
+        assert!(vec10.contains_subslice(&[3, 4]));
+        assert!(!vec10.contains_subslice(&[2, 4]));
+        let vec11: Vector<i8> = Vector::from_vec(&vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        let (chunks1, rem1) = vec11.chunks_exact(3);
+        assert_eq!(chunks1, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8]]);
+        assert_eq!(rem1, vec![9]);
+        let (chunks2, rem2) = vec11.chunks_exact(5);
+        assert_eq!(chunks2.len(), 2);
+        assert!(rem2.is_empty());
+        #[derive(Clone, Debug)]
+        struct Tagged { key: i8, tag: char }
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool { self.key == other.key }
+        }
+        impl PartialOrd for Tagged {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                self.key.partial_cmp(&other.key)
+            }
+        }
+        let tagged: Vec<Tagged> = vec![
+            Tagged { key: 2, tag: 'a' }, Tagged { key: 1, tag: 'b' }, Tagged { key: 2, tag: 'c' },
+            Tagged { key: 1, tag: 'd' }, Tagged { key: 2, tag: 'e' }, Tagged { key: 0, tag: 'f' },
+        ];
+        let mut vec12: Vector<Tagged> = Vector::from_vec(&tagged);
+        vec12.sort();
+        let tags: Vec<char> = vec12.to_vec().iter().map(|t| t.tag).collect();
+        assert_eq!(tags, vec!['f', 'b', 'd', 'a', 'c', 'e']);
+        let mut vec13: Vector<Tagged> = Vector::from_vec(&tagged);
+        vec13.sort_unstable();
+        assert!(vec13.is_sorted());
+        let keys: Vec<i8> = vec13.to_vec().iter().map(|t| t.key).collect();
+        assert_eq!(keys, vec![0, 1, 1, 2, 2, 2]);
+        let vec14: Vector<i8> = (0..5).collect();
+        assert_eq!(vec14.fold(0, |acc, x| acc + x), 10);
+        assert_eq!(vec14.fold(0i32, |acc, x| acc.max(*x as i32)), 4);
+        assert_eq!(vec14.to_vec(), vec![0, 1, 2, 3, 4]);
+        let vec15: Vector<i8> = Vector::from_vec(&vec![0, 1, 2, 3]);
+        assert_eq!(vec15.iter_rev().copied().collect::<Vec<i8>>(), vec![3, 2, 1, 0]);
+        assert_eq!(vec15.to_vec(), vec![0, 1, 2, 3]);
+        let vec16: Vector<i32> = Vector::from_vec(&vec![1, 2, 3, 4, 5]);
+        assert_eq!(vec16.find(|x| *x > 3), Some((3, &4)));
+        assert_eq!(vec16.rfind(|x| *x > 3), Some((4, &5)));
+        assert_eq!(vec16.find(|x| *x > 5), None);
+        assert_eq!(Vector::<i32>::new().rfind(|_| true), None);
+        let mut vec17: Vector<i32> = Vector::from_vec(&vec![1, 2, 3, 4, 5]);
+        assert_eq!(vec17.swap_remove(1), 2);
+        assert_eq!(vec17.to_vec(), vec![1, 5, 3, 4]);
+        assert_eq!(vec17.swap_remove(3), 4);
+        assert_eq!(vec17.to_vec(), vec![1, 5, 3]);
+        let vec18: Vector<f64> = Vector::from_vec(&vec![1.0, 2.5, -3.0]);
+        let vec19: Vector<f64> = Vector::from_vec(&vec![1.0 + 1e-9, 2.5, -3.0 - 1e-9]);
+        assert!(vec18.approx_eq(&vec19, 1e-6));
+        assert!(!vec18.approx_eq(&vec19, 1e-12));
+        assert!(!vec18.approx_eq(&Vector::from_vec(&vec![1.0, 2.5]), 1e-6));
+        let vec20: Vector<f64> = Vector::from_vec(&vec![1.0, f64::NAN, -3.0]);
+        assert!(!vec20.approx_eq(&vec20, 1e-6));
+        assert!(!vec18.approx_eq(&vec20, 1e-6));
+        let vec21: Vector<f64> = Vector::from_vec(&vec![1.0, 2.5, f64::NEG_INFINITY]);
+        assert!(vec21.approx_eq(&vec21, 1e-6));
+        assert!(!vec21.approx_eq(&Vector::from_vec(&vec![1.0, 2.5, f64::INFINITY]), 1e-6));
+    }
+}
+
+#[cfg(test)]
+mod no_std_tests {
+    use crate::prelude::*;
+    use crate::collection::*;
+    use crate::array::*;
+    use crate::array::list::*;
+    use crate::array::list::vector::*;
+    use crate::grid::*;
+    use crate::kv;
+    use crate::map::*;
+    use crate::map::traversable::*;
+    use crate::map::traversable::graph::*;
+    use crate::map::traversable::linked::*;
+    use crate::queue::*;
+    use crate::queue::deque::*;
+    use crate::set::*;
+    use crate::stack::*;
+    use len_trait::*;
+
+    #[test]
+    fn core_collections_test() {
+        let arr1: Array<i8, 3> = Array::from_vec(&vec![1, 2, 3]);
+        assert_eq!(arr1.len(), 3);
+        let list1: List<i8> = List::from_vec(&vec![1, 2, 3]);
+        assert_eq!(list1.to_vec(), vec![1, 2, 3]);
+        let mut vec1: Vector<i8> = Vector::new();
+        vec1.append(1);
+        assert_eq!(vec1.to_vec(), vec![1]);
+        let mut queue1: Queue<i8> = Queue::new();
+        queue1.enqueue(1);
+        assert_eq!(queue1.dequeue(), Some(1));
+        let mut deque1: Deque<i8> = Deque::new();
+        deque1.enqueue(1);
+        assert_eq!(deque1.pop_last(), Some(1));
+        let mut stack1: Stack<i8> = Stack::new();
+        stack1.push(1);
+        assert_eq!(stack1.pop(), Some(1));
+        let mut set1: Set<i8> = Set::new();
+        set1.add(1);
+        assert!(set1.contains(&1));
+        let mut map1: Map<i8, String> = Map::new();
+        map1.insert(kv!(1, (String::from("one"))));
+        assert_eq!(map1.get(1), Some(&String::from("one")));
+        let mut grid1: Grid<i8> = Grid::new();
+        grid1.resize(2, 2);
+        assert_eq!(grid1.size(), 4);
+        let dlist1: DoublyLinkedList<i8> = DoublyLinkedList::from_vec(&[1, 2]);
+        assert_eq!(dlist1.len(), 2);
+        let mut graph1: UUGraph<i8> = Graph::new();
+        graph1.insert(kv!(0, 1));
+        graph1.insert(kv!(1, 2));
+        graph1.connect(UUGraphEdge::new(0, 1));
+        assert!(graph1.is_neighbor(0, 1));
     }
 }
@@ -10,14 +10,17 @@
 
 pub mod traversable;
 
-use core::fmt::{Debug, Formatter};
-use std::cmp::Ordering;
-use std::hash::Hash;
-use std::ops::{Index, IndexMut};
+use core::fmt::{Debug, Display, Formatter};
+use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::ops::{Index, IndexMut};
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::prelude::*;
 
 /// Contains a key/value pair.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct KeyValue<K, V>
     where
@@ -30,15 +33,61 @@ pub struct KeyValue<K, V>
     pub value: V,
 }
 
+// Eq function for KeyValue
+impl<K, V> Eq for KeyValue<K, V>
+    where
+        K: Eq + PartialOrd + Clone + Debug,
+        V: Eq + PartialOrd + Clone + Debug,
+{}
+
+// Ord function for KeyValue
+impl<K, V> Ord for KeyValue<K, V>
+    where
+        K: Ord + Clone + Debug,
+        V: Ord + Clone + Debug,
+{
+    /// Returns the ordering of this key/value pair compared to another key/value pair, ordering
+    /// by key and then by value if the keys are equal.
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key).then_with(|| self.value.cmp(&other.value))
+    }
+}
+
 // PartialOrd function for KeyValue
 impl<K, V> PartialOrd for KeyValue<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug,
         V: PartialEq + PartialOrd + Clone + Debug,
 {
-    /// Returns the ordering of this key/value pair compared to another key/value pair.
+    /// Returns the ordering of this key/value pair compared to another key/value pair, ordering
+    /// by key and then by value if the keys are equal. Returns None if the keys or values are
+    /// incomparable.
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.key.partial_cmp(&other.key)
+        match self.key.partial_cmp(&other.key) {
+            Some(Ordering::Equal) => self.value.partial_cmp(&other.value),
+            ord => ord,
+        }
+    }
+}
+
+// KeyValue functions
+impl<K, V> KeyValue<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug,
+        V: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Returns the ordering of this key/value pair compared to another key/value pair by their
+    /// keys only. Incomparable keys are considered less than all other keys.
+    #[allow(dead_code)]
+    pub fn cmp_by_key(&self, other: &Self) -> Ordering {
+        self.key.partial_cmp(&other.key).unwrap_or(Ordering::Less)
+    }
+
+    /// Returns the ordering of this key/value pair compared to another key/value pair by their
+    /// values only. Incomparable values are considered less than all other values.
+    #[allow(dead_code)]
+    pub fn cmp_by_value(&self, other: &Self) -> Ordering {
+        self.value.partial_cmp(&other.value).unwrap_or(Ordering::Less)
     }
 }
 
@@ -78,6 +127,7 @@ pub trait MapCollection<K, V>: Collection + Index<K> + IndexMut<K>
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A 'map' is a 'collection' of key/value pairs where each key is unique and has an associated
 /// value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Map<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug,
@@ -127,6 +177,40 @@ impl<K, V> Debug for Map<K, V>
     }
 }
 
+// Default function for Map
+impl<K, V> Default for Map<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns a new empty 'map'.
+    fn default() -> Self {
+        Map::new()
+    }
+}
+
+// Display function for Map
+impl<K, V> Display for Map<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug + Display,
+        V: Clone + Debug + PartialEq + PartialOrd + Display,
+{
+    /// Displays this 'map' in a compact, human-readable form, such as {k: v, ...}.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{")?;
+
+        for i in 0..self.arr.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}: {}", self.arr[i].key, self.arr[i].value)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
 // Empty function for Map
 impl<K, V> Empty for Map<K, V>
     where
@@ -139,6 +223,35 @@ impl<K, V> Empty for Map<K, V>
     }
 }
 
+// Extend function for Map
+impl<K, V> Extend<KeyValue<K, V>> for Map<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Extends this 'map' with the key/value pairs of the specified iterator. Pairs with a key
+    /// that already exists in this 'map' are not inserted.
+    fn extend<I: IntoIterator<Item = KeyValue<K, V>>>(&mut self, iter: I) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+// FromIterator function for Map
+impl<K, V> FromIterator<KeyValue<K, V>> for Map<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Creates a new 'map' that contains the key/value pairs of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = KeyValue<K, V>>>(iter: I) -> Self {
+        let mut map: Map<K, V> = Map::new();
+        map.extend(iter);
+        map
+    }
+}
+
 // Index function for Map
 impl<K, V> Index<K> for Map<K, V>
     where
@@ -458,6 +571,62 @@ impl<K, V> Map<K, V>
 
         map
     }
+
+    /// Returns true if this 'map' contains the specified key. This is the same as 'exists', but
+    /// takes the key by reference.
+    #[allow(dead_code)]
+    pub fn contains_key(&self, key: &K) -> bool {
+        // If an entry's key matches the key, return true.
+        for i in 0..self.arr.len() {
+            if self.arr[i].key == *key {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns true if any entry in this 'map' has the specified value.
+    #[allow(dead_code)]
+    pub fn contains_value(&self, value: &V) -> bool {
+        // If an entry's value matches the value, return true.
+        for i in 0..self.arr.len() {
+            if self.arr[i].value == *value {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Returns true if the entries in this 'map' are sorted by value in ascending order.
+    #[allow(dead_code)]
+    pub fn is_sorted_by_value(&self) -> bool {
+        // If a value is greater than the next, return false.
+        for i in 1..self.arr.len() {
+            if self.arr[i - 1].value > self.arr[i].value {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Sorts the entries in this 'map' by value in ascending order. Entries with equal values keep
+    /// their relative order. Incomparable values are considered less than all other values.
+    #[allow(dead_code)]
+    pub fn sort_by_value(&mut self) {
+        self.arr.sort_by(|a, b| a.cmp_by_value(b));
+    }
+
+    /// Sorts the entries in this 'map' by value in descending order. Incomparable values are
+    /// considered less than all other values.
+    #[allow(dead_code)]
+    pub fn sort_by_value_rev(&mut self) {
+        self.arr.sort_by(|a, b| a.cmp_by_value(b));
+        // Reverse the order of the entries to get a reverse sorted map.
+        self.arr.reverse();
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -471,6 +640,7 @@ macro_rules! dkv {
 }
 
 /// A 'dictionary' is a 'collection' containing 'string' label keys associated with a value.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Dictionary<V>
     where
         V: PartialEq + PartialOrd + Clone + Debug,
@@ -516,6 +686,38 @@ impl<V> Debug for Dictionary<V>
     }
 }
 
+// Default function for Dictionary
+impl<V> Default for Dictionary<V>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns a new empty 'dictionary'.
+    fn default() -> Self {
+        Dictionary::new()
+    }
+}
+
+// Display function for Dictionary
+impl<V> Display for Dictionary<V>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd + Display,
+{
+    /// Displays this 'dictionary' in a compact, human-readable form, such as {k: v, ...}.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{")?;
+
+        for i in 0..self.arr.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}: {}", self.arr[i].key, self.arr[i].value)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
 // Empty function for Dictionary
 impl<V> Empty for Dictionary<V>
     where
@@ -884,12 +1086,41 @@ impl<V> Dictionary<V>
 
         dict
     }
+
+    /// Returns the specified number of entries in this 'dictionary' with the largest values, in
+    /// descending order of value. Entries with equal values are ordered by key. If the number is
+    /// greater than the length of this 'dictionary', all entries are returned. This 'dictionary'
+    /// is not changed.
+    #[allow(dead_code)]
+    pub fn top_n(&self, n: usize) -> Vec<KeyValue<String, V>> {
+        // If no entries are requested, return an empty vector.
+        if n == 0 {
+            return Vec::new();
+        }
+
+        let mut vec: Vec<KeyValue<String, V>> = self.arr.clone();
+        let order = |a: &KeyValue<String, V>, b: &KeyValue<String, V>| {
+            b.cmp_by_value(a).then_with(|| a.key.cmp(&b.key))
+        };
+
+        // Partition the n largest entries to the front before sorting only those entries.
+        if n < vec.len() {
+            vec.select_nth_unstable_by(n - 1, order);
+            vec.truncate(n);
+        }
+
+        vec.sort_by(order);
+
+        vec
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // HashMap
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A map structure with hashed keys that allow for faster value retrieval.
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashMap<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
@@ -900,6 +1131,7 @@ pub struct HashMap<K, V>
 }
 
 // Clear function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Clear for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -910,6 +1142,7 @@ impl<K, V> Clear for HashMap<K, V>
 }
 
 // Clone function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Clone for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -924,6 +1157,7 @@ impl<K, V> Clone for HashMap<K, V>
 }
 
 // Debug function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Debug for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -937,7 +1171,45 @@ impl<K, V> Debug for HashMap<K, V>
     }
 }
 
+// Default function for HashMap
+#[cfg(feature = "std")]
+impl<K, V> Default for HashMap<K, V>
+    where
+        K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns a new empty 'hash map'.
+    fn default() -> Self {
+        HashMap::new()
+    }
+}
+
+// Display function for HashMap
+#[cfg(feature = "std")]
+impl<K, V> Display for HashMap<K, V>
+    where
+        K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd + Display,
+        V: Clone + Debug + PartialEq + PartialOrd + Display,
+{
+    /// Displays this 'hash map' in a compact, human-readable form, such as {k: v, ...}. The
+    /// order of the key/value pairs is not guaranteed.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{")?;
+
+        for (i, (k, v)) in self.map.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}: {}", k, v)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
 // Empty function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Empty for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -947,7 +1219,39 @@ impl<K, V> Empty for HashMap<K, V>
     fn is_empty(&self) -> bool { self.map.is_empty() }
 }
 
+// Extend function for HashMap
+#[cfg(feature = "std")]
+impl<K, V> Extend<KeyValue<K, V>> for HashMap<K, V>
+    where
+        K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Extends this 'hash map' with the key/value pairs of the specified iterator. Pairs with a key
+    /// that already exists in this 'hash map' are not inserted.
+    fn extend<I: IntoIterator<Item = KeyValue<K, V>>>(&mut self, iter: I) {
+        for i in iter {
+            self.insert(i);
+        }
+    }
+}
+
+// FromIterator function for HashMap
+#[cfg(feature = "std")]
+impl<K, V> FromIterator<KeyValue<K, V>> for HashMap<K, V>
+    where
+        K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Creates a new 'hash map' that contains the key/value pairs of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = KeyValue<K, V>>>(iter: I) -> Self {
+        let mut hmap: HashMap<K, V> = HashMap::new();
+        hmap.extend(iter);
+        hmap
+    }
+}
+
 // Index function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Index<K> for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -970,6 +1274,7 @@ impl<K, V> Index<K> for HashMap<K, V>
 }
 
 // IndexMut function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> IndexMut<K> for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -989,6 +1294,7 @@ impl<K, V> IndexMut<K> for HashMap<K, V>
 }
 
 // IntoIterator function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> IntoIterator for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -1013,6 +1319,7 @@ impl<K, V> IntoIterator for HashMap<K, V>
 }
 
 // Len function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Len for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -1023,6 +1330,7 @@ impl<K, V> Len for HashMap<K, V>
 }
 
 // PartialEq function for HashMap
+#[cfg(feature = "std")]
 impl<K, V> PartialEq for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -1033,6 +1341,7 @@ impl<K, V> PartialEq for HashMap<K, V>
 }
 
 // Collection functions for HashMap
+#[cfg(feature = "std")]
 impl<K, V> Collection for HashMap<K, V>
     where
         K: Clone + Debug + Eq + Hash + PartialEq + PartialOrd,
@@ -1080,6 +1389,7 @@ impl<K, V> Collection for HashMap<K, V>
 }
 
 // MapCollection functions for HashMap
+#[cfg(feature = "std")]
 impl<K, V> MapCollection<K, V> for HashMap<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
@@ -1132,6 +1442,7 @@ impl<K, V> MapCollection<K, V> for HashMap<K, V>
 }
 
 // HashMap functions
+#[cfg(feature = "std")]
 impl<K, V> HashMap<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
@@ -1151,4 +1462,30 @@ impl<K, V> HashMap<K, V>
 
         hmap
     }
+
+    /// Returns true if this 'hash map' contains the specified key. This is the same as 'exists',
+    /// but takes the key by reference.
+    #[allow(dead_code)]
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns true if any entry in this 'hash map' has the specified value.
+    #[allow(dead_code)]
+    pub fn contains_value(&self, value: &V) -> bool {
+        self.map.values().any(|v| v == value)
+    }
+
+    /// Reserves capacity for at least the specified number of additional entries in this
+    /// 'hash map', so that inserting them does not need to rehash.
+    #[allow(dead_code)]
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional)
+    }
+
+    /// Creates a new empty 'hash map' with capacity for at least the specified number of entries.
+    #[allow(dead_code)]
+    pub fn with_capacity(capacity: usize) -> Self {
+        HashMap { map: std::collections::HashMap::with_capacity(capacity) }
+    }
 }
\ No newline at end of file
@@ -4,6 +4,7 @@
 //! Also contains a 'node' struct used by 'traversable collections' and 'traverser' traits for
 //! 'traversable collections' which implement those 'traversers'.
 
+#[cfg(feature = "std")]
 pub mod tree;
 pub mod linked;
 pub mod graph;
@@ -11,9 +12,11 @@ pub mod graph;
 use core::fmt::Debug;
 use crate::map::*;
 use crate::map::traversable::linked::DoublyLinkedList;
+use crate::prelude::*;
 
 /// Contains data for a 'node' in a 'traversable collection', as well as a list of 'nodes' that
 /// it is linked to.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, PartialOrd)]
 pub struct Node<K, V>
     where
@@ -266,5 +269,5 @@ pub trait TraversableCollection<K, V>: MapCollection<K, V> + IntoTraverser<K>
     /// second specified key. Returns None if there is no path. The path contains the
     /// key/value pairs of each 'node' in the path and is stored in order from key_a at the
     /// start to key_b at the end.
-    fn path_of(&mut self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>>;
+    fn path_of(&self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>>;
 }
@@ -6,7 +6,7 @@
 //! are linked together with edges.
 
 use core::fmt::{Debug, Formatter};
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 use len_trait::*;
 use crate::collection::*;
 use crate::grid::*;
@@ -14,7 +14,9 @@ use crate::kv;
 use crate::map::traversable::*;
 use crate::map::traversable::linked::*;
 use crate::queue::*;
+use crate::set::*;
 use crate::stack::*;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a 'graph collection'.
 pub trait GraphCollection<V>: TraversableCollection<usize, V>
@@ -147,7 +149,8 @@ GraphTraverser<V, DIRECTED, WEIGHTED>
     }
 
     /// Sets the 'graph traversal mode' of this 'graph traverser' to follow depth first
-    /// traversal.
+    /// traversal. Each 'node' is followed as deep as possible before its siblings, starting
+    /// with its highest keyed neighbor.
     fn dfs(&mut self) {
         if self.mode != GraphTraversalMode::Dfs {
             self.mode = GraphTraversalMode::Dfs;
@@ -161,7 +164,8 @@ GraphTraverser<V, DIRECTED, WEIGHTED>
     }
 
     /// Sets the 'graph traversal mode' of this 'graph traverser' to follow depth first
-    /// traversal for all 'nodes', meaning it will traverse disconnected 'nodes'.
+    /// traversal for all 'nodes', meaning it will traverse disconnected 'nodes'. Neighbors are
+    /// visited in the same order as 'dfs'.
     fn dfs_all(&mut self) {
         if self.mode != GraphTraversalMode::DfsAll {
             self.mode = GraphTraversalMode::DfsAll;
@@ -350,6 +354,11 @@ pub type DUGraphEdge = DUEdge<usize>;
 #[allow(dead_code)]
 pub type DWGraphEdge = DWEdge<usize>;
 
+/// A cut type, holding the total weight of the cut 'edges' and the two sets of 'node' keys the
+/// cut partitions a 'graph' into.
+#[allow(dead_code)]
+pub type GraphCut = (f32, (Set<usize>, Set<usize>));
+
 // Clear function for Graph
 impl<V, const DIRECTED: bool, const WEIGHTED: bool> Clear for Graph<V, DIRECTED, WEIGHTED>
     where
@@ -646,23 +655,13 @@ Graph<V, DIRECTED, WEIGHTED>
     }
 
     /// Returns the diameter of the 'graph'. The diameter of a 'graph' is the longest path
-    /// from one 'node' to another 'node'.
+    /// from one 'node' to another 'node'. For a disconnected 'graph', only paths between
+    /// 'nodes' that can reach each other are considered.
     fn diameter(&self) -> f32 {
         let mut max: f32 = 0.0;
 
         for i in 0..self.nodes.len() {
-            let mut node: Node<usize, V> = Node {
-                pair: kv!(i, (self.nodes[i].clone())),
-                links: Vec::new(),
-            };
-
-            for j in 0..self.amtx.columns() {
-                if self.amtx[(i, j)] != 0.0 {
-                    node.links.push(Some(j));
-                }
-            }
-
-            let ecc: f32 = self.eccentricity(&node).unwrap();
+            let ecc: f32 = self.component_eccentricity(&self.node(i).unwrap());
             if ecc > max { max = ecc; }
         }
 
@@ -814,7 +813,7 @@ Graph<V, DIRECTED, WEIGHTED>
     /// start to key_b at the end. This function uses Dijkstra's algorithm if this 'graph'
     /// on has positive weights, otherwise it uses Bellman Ford's algorithm to find the
     /// shortest path.
-    fn path_of(&mut self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+    fn path_of(&self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
         // If either node key is not in this graph, return None.
         if key_a >= self.nodes.len() || key_b >= self.nodes.len() {
             return None;
@@ -957,24 +956,20 @@ Graph<V, DIRECTED, WEIGHTED>
 {
     /// Returns a list of 'nodes' that are the center of this 'graph'. The center of a
     /// 'graph' is the 'node' or 'nodes' with the minimum eccentricity to all other
-    /// 'nodes'.
+    /// 'nodes'. For a disconnected 'graph', each 'node's' eccentricity is measured within
+    /// its own component, meaning only the 'nodes' reachable from it are considered.
     fn center(&self) -> Vec<Node<usize, V>> {
         // Get the radius of the graph.
         let r: f32 = self.radius();
 
-        // Collect all nodes that have an eccentricity matching the radius.
+        // Collect all nodes that have a component eccentricity matching the radius.
         let mut vec: Vec<Node<usize, V>> = Vec::new();
 
         for i in 0..self.nodes.len() {
             let node: Node<usize, V> = self.node(i).unwrap();
 
-            match self.eccentricity(&node) {
-                Some(ecc) => {
-                    if ecc == r {
-                        vec.push(node.clone());
-                    }
-                },
-                None => {},
+            if self.component_eccentricity(&node) == r {
+                vec.push(node.clone());
             }
         }
 
@@ -1088,7 +1083,7 @@ Graph<V, DIRECTED, WEIGHTED>
 
     /// Returns the eccentricity of the specified 'node'. The eccentricity is the 'nodes'
     /// maximum distance to all other 'nodes' in the 'graph'. If the 'node' is not in the
-    /// 'graph', this returns None.
+    /// 'graph', or if any other 'node' cannot be reached from it, this returns None.
     fn eccentricity(&self, node: &Node<usize, V>) -> Option<f32> {
         // Return None if the specified node is not in the graph.
         if node.pair.key.clone() >= self.nodes.len() {
@@ -1100,13 +1095,9 @@ Graph<V, DIRECTED, WEIGHTED>
         // For all other nodes in the graph.
         for i in 0..self.nodes.len() {
             if i != node.pair.key.clone() {
-                let mut dist: f32 = 0.0;
-
-                // Calculate the distance between the specified node and another node.
-                match self.distance(&node, &self.node(i).unwrap()) {
-                    Some(d) => dist = d,
-                    None => {},
-                }
+                // Calculate the distance between the specified node and another node. If the
+                // other node is unreachable, return None.
+                let dist: f32 = self.distance(node, &self.node(i).unwrap())?;
 
                 // Update the max distance.
                 if dist > max {
@@ -1147,13 +1138,14 @@ Graph<V, DIRECTED, WEIGHTED>
     }
 
     /// Returns the radius of this 'graph'. The radius of a 'graph' is the smallest
-    /// maximum distance or eccentricity between all the 'nodes'.
+    /// maximum distance or eccentricity between all the 'nodes'. For a disconnected
+    /// 'graph', each 'node's' eccentricity is measured within its own component.
     fn radius(&self) -> f32 {
         let mut min: f32 = f32::MAX;
 
-        // For each node, get its eccentricity.
+        // For each node, get its eccentricity within its component.
         for i in 0..self.nodes.len() {
-            let ecc: f32 = self.eccentricity(&self.node(i).unwrap()).unwrap();
+            let ecc: f32 = self.component_eccentricity(&self.node(i).unwrap());
 
             // Find the minimum eccentricity value.
             if ecc <= min {
@@ -1175,11 +1167,175 @@ impl<V, const DIRECTED: bool, const WEIGHTED: bool> Graph<V, DIRECTED, WEIGHTED>
     #[allow(dead_code)]
     pub fn new() -> Self {
         Graph {
-            amtx: AdjacencyMatrix::new(),
+            amtx: AdjacencyMatrix::new(0),
             nodes: Vec::new(),
         }
     }
 
+    /// Returns the average shortest path distance between every ordered pair of distinct
+    /// 'nodes' in this 'graph'. Returns None if any 'node' cannot reach another 'node', or if
+    /// this 'graph' has fewer than two 'nodes'.
+    #[allow(dead_code)]
+    pub fn avg_path_length(&self) -> Option<f32> {
+        // If there are no pairs of nodes, return None.
+        if self.nodes.len() < 2 {
+            return None;
+        }
+
+        let mut total: f32 = 0.0;
+
+        // Add the distance between each pair of nodes.
+        for i in 0..self.nodes.len() {
+            for j in 0..self.nodes.len() {
+                if i != j {
+                    match self.distance(&self.node(i).unwrap(), &self.node(j).unwrap()) {
+                        Some(d) => total += d,
+                        // If a node cannot reach another node, return None.
+                        None => return None,
+                    }
+                }
+            }
+        }
+
+        Some(total / (self.nodes.len() * (self.nodes.len() - 1)) as f32)
+    }
+
+    /// Returns the keys of the 'nodes' in this 'graph' in breadth first order, starting from
+    /// the 'node' with the specified key. Only 'nodes' reachable from the start 'node' are
+    /// included. If the start 'node' does not exist, an empty vector is returned.
+    #[allow(dead_code)]
+    pub fn bfs_order(&self, start: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::new();
+
+        // If the start node does not exist, return an empty vector.
+        if start >= self.nodes.len() {
+            return order;
+        }
+
+        let mut visited: Vec<bool> = vec![false; self.nodes.len()];
+        let mut queue: Queue<usize> = Queue::with_capacity(self.nodes.len());
+
+        // Visit start node.
+        visited[start] = true;
+        queue.enqueue(start);
+
+        // Visit all nodes connected to the current node.
+        while !queue.is_empty() {
+            // Add node to order and remove it from the queue.
+            let n: usize = queue.dequeue().unwrap();
+            order.push(n);
+
+            // Add unvisited neighbors of the current node to the queue.
+            for (i, seen) in visited.iter_mut().enumerate() {
+                if self.amtx[(n, i)] != 0.0 && !*seen {
+                    *seen = true;
+                    queue.enqueue(i);
+                }
+            }
+        }
+
+        order
+    }
+
+    /// Returns the keys of the 'nodes' in this 'graph' grouped into layers by their unweighted
+    /// distance from the 'node' with the specified key, so layer 0 contains only the start
+    /// 'node'. 'Nodes' that cannot be reached from the start 'node' are not included. If the
+    /// start 'node' does not exist, an empty vector is returned.
+    #[allow(dead_code)]
+    pub fn bfs_layers(&self, start: usize) -> Vec<Vec<usize>> {
+        let mut layers: Vec<Vec<usize>> = Vec::new();
+
+        // If the start node does not exist, return an empty vector.
+        if start >= self.nodes.len() {
+            return layers;
+        }
+
+        let mut visited: Vec<bool> = vec![false; self.nodes.len()];
+
+        // Visit start node as the first layer.
+        visited[start] = true;
+        layers.push(vec![start]);
+
+        // Build the next layer from the unvisited neighbors of the nodes in the current layer.
+        loop {
+            let mut next: Vec<usize> = Vec::new();
+            let curr: &Vec<usize> = &layers[layers.len() - 1];
+
+            for &n in curr.iter() {
+                for (j, seen) in visited.iter_mut().enumerate() {
+                    if self.amtx[(n, j)] != 0.0 && !*seen {
+                        *seen = true;
+                        next.push(j);
+                    }
+                }
+            }
+
+            // If no new nodes were reached, return the layers.
+            if next.is_empty() {
+                return layers;
+            }
+
+            layers.push(next);
+        }
+    }
+
+    /// Returns the two disjoint sets of 'node' keys that partition this 'graph' if it is
+    /// bipartite, meaning every 'edge' connects a 'node' in one set to a 'node' in the other.
+    /// 'Edge' direction is ignored. Returns None if this 'graph' is not bipartite.
+    #[allow(dead_code)]
+    pub fn bipartition(&self) -> Option<(Set<usize>, Set<usize>)> {
+        let mut color: Vec<i8> = vec![0; self.nodes.len()];
+        let mut queue: Queue<usize> = Queue::with_capacity(self.nodes.len());
+
+        // Color every component, starting from each node that has not been colored yet.
+        for i in 0..self.nodes.len() {
+            if color[i] != 0 {
+                continue;
+            }
+
+            // Color first node of the component.
+            color[i] = 1;
+            queue.enqueue(i);
+
+            // Color all nodes connected to the current node.
+            while !queue.is_empty() {
+                // Get node from queue.
+                let n: usize = queue.dequeue().unwrap();
+
+                for j in 0..self.amtx.columns() {
+                    if self.amtx[(n, j)] != 0.0 || self.amtx[(j, n)] != 0.0 {
+                        // If neighbor node is not colored, set its color to the opposite of the
+                        // current node's color and add it to the queue.
+                        if color[j] == 0 {
+                            color[j] = 3 - color[n];
+                            queue.enqueue(j);
+                        }
+                        // If neighbor node's color is the same as the current node's, return
+                        // None.
+                        else if color[j] == color[n] {
+                            return None;
+                        }
+                    }
+                }
+            }
+        }
+
+        // Split the nodes into sets by color.
+        let mut set_a: Set<usize> = Set::new();
+        let mut set_b: Set<usize> = Set::new();
+
+        for (i, c) in color.iter().enumerate() {
+            if *c == 1 {
+                set_a.add(i);
+            }
+            else {
+                set_b.add(i);
+            }
+        }
+
+        Some((set_a, set_b))
+    }
+
     /// Creates a connection using the specified 'edge'. Returns true if successful.
     /// Returns false if either 'node' specified in the 'edge' does not exist in this
     /// 'graph'. If this 'graph' is a directed 'graph', only an 'edge' from 'node' a to
@@ -1221,6 +1377,124 @@ impl<V, const DIRECTED: bool, const WEIGHTED: bool> Graph<V, DIRECTED, WEIGHTED>
         true
     }
 
+    /// Returns the eccentricity of the specified 'node' within its component, meaning the
+    /// 'nodes' maximum distance to all other 'nodes' that can be reached from it. A 'node'
+    /// that cannot reach any other 'node' has an eccentricity of 0.0.
+    fn component_eccentricity(&self, node: &Node<usize, V>) -> f32 {
+        let mut max: f32 = 0.0;
+
+        // For all other nodes in the graph.
+        for i in 0..self.nodes.len() {
+            if i != node.pair.key {
+                // Calculate the distance between the specified node and another node. Nodes that
+                // cannot be reached count as a distance of 0.0, so they are skipped.
+                let dist: f32 = self.distance(node, &self.node(i).unwrap()).unwrap_or(0.0);
+
+                // Update the max distance.
+                if dist > max {
+                    max = dist;
+                }
+            }
+        }
+
+        max
+    }
+
+    /// Returns a 'map' from each degree found in this 'graph' to the number of 'nodes' that
+    /// have that degree. For a directed 'graph', the degree of a 'node' is its number of
+    /// outgoing 'edges'.
+    #[allow(dead_code)]
+    pub fn degree_histogram(&self) -> Map<usize, usize> {
+        let mut map: Map<usize, usize> = Map::new();
+
+        // Count each node's degree, adding the degree to the map if it's the first one found.
+        for i in 0..self.nodes.len() {
+            let degree: usize = self.degree_of(i) as usize;
+
+            match map.get(degree) {
+                Some(count) => { map.replace(kv!(degree, (count + 1))); },
+                None => { map.insert(kv!(degree, 1)); },
+            }
+        }
+
+        map
+    }
+
+    /// Returns the degree of every 'node' in this 'graph', sorted from highest to lowest. For a
+    /// directed 'graph', the degree of a 'node' is its number of outgoing 'edges'.
+    #[allow(dead_code)]
+    pub fn degree_sequence(&self) -> Vec<usize> {
+        let mut seq: Vec<usize> = Vec::new();
+
+        for i in 0..self.nodes.len() {
+            seq.push(self.degree_of(i) as usize);
+        }
+
+        seq.sort_by(|a, b| b.cmp(a));
+
+        seq
+    }
+
+    /// Returns the density of this 'graph', meaning the number of 'edges' divided by the
+    /// maximum number of 'edges' possible between its 'nodes'. Returns 0 if this 'graph' has
+    /// fewer than two 'nodes'.
+    #[allow(dead_code)]
+    pub fn density(&self) -> f32 {
+        // If there are no pairs of nodes, return 0.
+        if self.nodes.len() < 2 {
+            return 0.0;
+        }
+
+        let mut max: usize = self.nodes.len() * (self.nodes.len() - 1);
+
+        // An undirected edge connects a pair of nodes in both directions.
+        if !DIRECTED {
+            max /= 2;
+        }
+
+        self.edges() as f32 / max as f32
+    }
+
+    /// Returns the keys of the 'nodes' in this 'graph' in depth first order, starting from
+    /// the 'node' with the specified key. Only 'nodes' reachable from the start 'node' are
+    /// included. If the start 'node' does not exist, an empty vector is returned.
+    #[allow(dead_code)]
+    pub fn dfs_order(&self, start: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = Vec::new();
+
+        // If the start node does not exist, return an empty vector.
+        if start >= self.nodes.len() {
+            return order;
+        }
+
+        let mut visited: Vec<bool> = vec![false; self.nodes.len()];
+        let mut stack: Vec<usize> = Vec::new();
+
+        // Push start node onto stack.
+        stack.push(start);
+
+        while let Some(n) = stack.pop() {
+            // If the current node has already been visited, skip it.
+            if visited[n] {
+                continue;
+            }
+
+            // Visit current node and add it to order.
+            visited[n] = true;
+            order.push(n);
+
+            // Add unvisited neighbors of the current node to the stack in reverse, so the
+            // lowest keyed neighbor is visited first.
+            for i in (0..self.amtx.columns()).rev() {
+                if self.amtx[(n, i)] != 0.0 && !visited[i] {
+                    stack.push(i);
+                }
+            }
+        }
+
+        order
+    }
+
     /// Returns true if this 'graph' contains a cycle.
     fn is_cyclic(&self, node: usize, visited: &mut Vec<bool>, stack: &mut Vec<bool>) -> bool {
         if stack[node] { return true; }
@@ -1268,4 +1542,548 @@ impl<V, const DIRECTED: bool, const WEIGHTED: bool> Graph<V, DIRECTED, WEIGHTED>
 
         Some(n)
     }
+
+    /// Returns the keys of the 'nodes' along the shortest path from the 'node' with the first
+    /// specified key to the 'node' with the second specified key, including both 'nodes'. If
+    /// several paths share the minimum weight, the one with the fewest 'edges' is returned.
+    /// Returns None if either 'node' does not exist, if there is no path between them, or if a
+    /// negative cycle can be reached from the first 'node'.
+    #[allow(dead_code)]
+    pub fn shortest_path(&self, a: usize, b: usize) -> Option<Vec<usize>> {
+        self.shortest_path_avoiding(a, b, &Set::new())
+    }
+
+    /// Returns the keys of the 'nodes' along the shortest path from the 'node' with the first
+    /// specified key to the 'node' with the second specified key that does not pass through any
+    /// 'node' in the specified 'set' of blocked keys, including both end 'nodes'. The end 'nodes'
+    /// may be in the blocked 'set'. Returns None if either 'node' does not exist, if there is no
+    /// such path between them, or if a negative cycle can be reached from the first 'node'.
+    #[allow(dead_code)]
+    pub fn shortest_path_avoiding(&self, a: usize, b: usize, blocked: &Set<usize>)
+        -> Option<Vec<usize>> {
+        // If either node key is not in this graph, return None.
+        if a >= self.nodes.len() || b >= self.nodes.len() {
+            return None;
+        }
+
+        let mut dist: Vec<f32> = Vec::new();
+        let mut hops: Vec<usize> = Vec::new();
+        let mut open: Vec<bool> = Vec::new();
+        let mut prev: Vec<Option<usize>> = Vec::new();
+
+        // A node is open if the path may pass through it, which the end nodes always may.
+        for i in 0..self.nodes.len() {
+            dist.push(f32::INFINITY);
+            hops.push(usize::MAX);
+            open.push(i == a || i == b || !blocked.contains(&i));
+            prev.push(None);
+        }
+
+        // Set distance to key a to 0 (distance to self)
+        dist[a] = 0.0;
+        hops[a] = 0;
+
+        // If the graph has negative weights, use Bellman Ford's algorithm.
+        if self.has_neg_edges() {
+            for _ in 1..self.nodes.len() {
+                for i in 0..self.amtx.rows() {
+                    for j in 0..self.amtx.columns() {
+                        let w: f32 = self.amtx[(i, j)];
+
+                        // Relax the edge if it gives a lighter path, or an equally light path
+                        // with fewer edges.
+                        if w != 0.0 && open[j] && dist[i].is_finite() && (dist[i] + w < dist[j] ||
+                            (dist[i] + w == dist[j] && hops[i] + 1 < hops[j])) {
+                            dist[j] = dist[i] + w;
+                            hops[j] = hops[i] + 1;
+                            prev[j] = Some(i);
+                        }
+                    }
+                }
+            }
+
+            // If an edge can still be relaxed, there is a negative cycle, so return None.
+            for i in 0..self.amtx.rows() {
+                for j in 0..self.amtx.columns() {
+                    let w: f32 = self.amtx[(i, j)];
+
+                    if w != 0.0 && open[j] && dist[i].is_finite() && dist[i] + w < dist[j] {
+                        return None;
+                    }
+                }
+            }
+        }
+        // If the graph only has positive weights, use Dijkstra's algorithm.
+        else {
+            let mut visited: Vec<bool> = vec![false; self.nodes.len()];
+
+            for _ in 0..self.nodes.len() {
+                // Find the unvisited node with the minimum distance (then fewest edges) to
+                // node a.
+                let mut index: Option<usize> = None;
+
+                for i in 0..self.nodes.len() {
+                    if !visited[i] && dist[i].is_finite() {
+                        match index {
+                            Some(m) => {
+                                if dist[i] < dist[m] || (dist[i] == dist[m] && hops[i] < hops[m]) {
+                                    index = Some(i);
+                                }
+                            },
+                            None => index = Some(i),
+                        }
+                    }
+                }
+
+                // If no reachable node is left, stop searching.
+                let n: usize = match index {
+                    Some(m) => m,
+                    None => break,
+                };
+
+                // Mark the minimum distance node as visited.
+                visited[n] = true;
+
+                // Update distance of nodes adjacent to the minimum distance node that have not
+                // been visited.
+                for j in 0..self.nodes.len() {
+                    let w: f32 = self.amtx[(n, j)];
+
+                    if !visited[j] && open[j] && w != 0.0 && (dist[n] + w < dist[j] ||
+                        (dist[n] + w == dist[j] && hops[n] + 1 < hops[j])) {
+                        dist[j] = dist[n] + w;
+                        hops[j] = hops[n] + 1;
+                        prev[j] = Some(n);
+                    }
+                }
+            }
+        }
+
+        // If there is no path from node a to node b, return None.
+        if dist[b].is_infinite() {
+            return None;
+        }
+
+        // Rebuild the path by walking back from node b to node a.
+        let mut path: Vec<usize> = Vec::new();
+        let mut current: usize = b;
+        path.push(current);
+
+        while let Some(p) = prev[current] {
+            path.push(p);
+            current = p;
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+// Graph functions for directed Graph
+impl<V, const WEIGHTED: bool> Graph<V, true, WEIGHTED>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns true if this 'graph' is a directed acyclic 'graph', meaning following its 'edges'
+    /// can never lead back to a 'node' that was already visited.
+    #[allow(dead_code)]
+    pub fn is_dag(&self) -> bool {
+        !self.has_cycle()
+    }
+
+    /// Returns a vector containing the keys of the root 'nodes' in this 'graph', meaning the
+    /// 'nodes' that no 'edge' leads into, in ascending order.
+    #[allow(dead_code)]
+    pub fn roots(&self) -> Vec<usize> {
+        let mut roots: Vec<usize> = Vec::new();
+
+        // If no node has an edge to the current node, add it to the roots.
+        for j in 0..self.nodes.len() {
+            let mut root: bool = true;
+
+            for i in 0..self.nodes.len() {
+                if self.amtx[(i, j)] != 0.0 {
+                    root = false;
+                    break;
+                }
+            }
+
+            if root {
+                roots.push(j);
+            }
+        }
+
+        roots
+    }
+
+    /// Returns a vector containing the keys of the sink 'nodes' in this 'graph', meaning the
+    /// 'nodes' that no 'edge' leads out of, in ascending order.
+    #[allow(dead_code)]
+    pub fn sinks(&self) -> Vec<usize> {
+        let mut sinks: Vec<usize> = Vec::new();
+
+        // If the current node has no edge to another node, add it to the sinks.
+        for i in 0..self.nodes.len() {
+            if self.degree_of(i) == 0 {
+                sinks.push(i);
+            }
+        }
+
+        sinks
+    }
+
+    /// Returns the undirected, unweighted 'graph' underlying this 'graph'. It has the same
+    /// 'nodes', with an 'edge' between two 'nodes' wherever this 'graph' has an 'edge' between
+    /// them in either direction, so anti-parallel 'edges' are merged into one 'edge'.
+    #[allow(dead_code)]
+    pub fn to_undirected(&self) -> UUGraph<V> {
+        let mut graph: UUGraph<V> = Graph::new();
+
+        // Add all of this graph's nodes to the undirected graph.
+        for i in 0..self.nodes.len() {
+            graph.nodes.push(self.nodes[i].clone());
+            graph.amtx.add_node();
+        }
+
+        // Add an edge wherever two nodes are connected in either direction in this graph.
+        for i in 0..self.amtx.rows() {
+            for j in 0..self.amtx.columns() {
+                if self.amtx[(i, j)] != 0.0 || self.amtx[(j, i)] != 0.0 {
+                    graph.amtx[(i, j)] = 1.0;
+                }
+            }
+        }
+
+        graph
+    }
+}
+
+// Graph functions for undirected Graph
+impl<V, const WEIGHTED: bool> Graph<V, false, WEIGHTED>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Recursively assigns colors to the 'nodes' of this 'graph', starting from the 'node' with
+    /// the specified key, backtracking when a 'node' cannot be given any of the k colors.
+    /// Returns true if every 'node' from the specified key onward was colored.
+    fn color_rec(&self, node: usize, k: usize, colors: &mut Vec<Option<usize>>) -> bool {
+        // If every node has been colored, return true.
+        if node == self.nodes.len() {
+            return true;
+        }
+
+        for c in 0..k {
+            // Check that no neighbor of the current node already has this color.
+            let valid: bool = !colors.iter().enumerate()
+                .any(|(j, color)| j != node && self.amtx[(node, j)] != 0.0 && *color == Some(c));
+
+            // If the color is valid, try coloring the remaining nodes with it assigned.
+            if valid {
+                colors[node] = Some(c);
+
+                if self.color_rec(node + 1, k, colors) {
+                    return true;
+                }
+
+                colors[node] = None;
+            }
+        }
+
+        false
+    }
+
+    /// Returns the complement of this 'graph'. The complement has the same 'nodes', with an
+    /// 'edge' between two different 'nodes' only if there is no 'edge' between them in this
+    /// 'graph'. Every 'edge' in the complement has a weight of 1.0.
+    #[allow(dead_code)]
+    pub fn complement(&self) -> Self {
+        let mut graph: Self = Graph::new();
+
+        // Add all of this graph's nodes to the complement graph.
+        for i in 0..self.nodes.len() {
+            graph.nodes.push(self.nodes[i].clone());
+            graph.amtx.add_node();
+        }
+
+        // Add an edge wherever two different nodes are not connected in this graph.
+        for i in 0..self.amtx.rows() {
+            for j in 0..self.amtx.columns() {
+                if i != j && self.amtx[(i, j)] == 0.0 {
+                    graph.amtx[(i, j)] = 1.0;
+                }
+            }
+        }
+
+        graph
+    }
+
+    /// Returns an Eulerian path of this 'graph', meaning a path of 'node' keys that uses every
+    /// 'edge' exactly once, or None if there is no such path. A path exists if every 'node' with
+    /// an 'edge' is connected and either no 'node' or exactly two 'nodes' have an odd degree. If
+    /// no 'node' has an odd degree, the path is a circuit that ends where it starts. Returns None
+    /// if this 'graph' has no 'edges'.
+    #[allow(dead_code)]
+    pub fn eulerian_path(&self) -> Option<Vec<usize>> {
+        let n: usize = self.nodes.len();
+        let mut adj: Vec<Vec<bool>> = Vec::new();
+        let mut degree: Vec<usize> = Vec::new();
+
+        // Copy the edges of this graph, counting a self loop twice towards a node's degree.
+        for i in 0..n {
+            let mut row: Vec<bool> = Vec::new();
+            let mut d: usize = 0;
+
+            for j in 0..n {
+                row.push(self.amtx[(i, j)] != 0.0);
+
+                if row[j] {
+                    d += if i == j { 2 } else { 1 };
+                }
+            }
+
+            adj.push(row);
+            degree.push(d);
+        }
+
+        // Find the start node, which must be a node with an odd degree if there is one.
+        let mut start: Option<usize> = None;
+        let mut odd: usize = 0;
+
+        for (i, d) in degree.iter().enumerate() {
+            if d % 2 == 1 {
+                if odd == 0 {
+                    start = Some(i);
+                }
+
+                odd += 1;
+            }
+            else if *d > 0 && start.is_none() {
+                start = Some(i);
+            }
+        }
+
+        // If there are no edges or the wrong number of odd degree nodes, return None.
+        let start: usize = match start {
+            Some(s) if odd == 0 || odd == 2 => s,
+            _ => return None,
+        };
+
+        // If a node with an edge cannot be reached from the start node, return None.
+        let reachable: Vec<usize> = self.bfs_order(start);
+
+        for (i, d) in degree.iter().enumerate() {
+            if *d > 0 && !reachable.contains(&i) {
+                return None;
+            }
+        }
+
+        // Build the path using Hierholzer's algorithm, removing each edge as it is followed.
+        let mut path: Vec<usize> = Vec::new();
+        let mut stack: Vec<usize> = vec![start];
+
+        while let Some(&u) = stack.last() {
+            let next: Option<usize> = adj[u].iter().position(|edge| *edge);
+
+            match next {
+                // Follow an unused edge from the current node.
+                Some(v) => {
+                    adj[u][v] = false;
+                    adj[v][u] = false;
+                    stack.push(v);
+                },
+                // If the current node has no unused edges, add it to the path.
+                None => {
+                    path.push(u);
+                    stack.pop();
+                },
+            }
+        }
+
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Attempts to color the 'nodes' of this 'graph' using at most the specified number of
+    /// colors, so that no two neighboring 'nodes' share a color. Returns a 'map' of each 'node'
+    /// key to its color (from 0 to k - 1) if such a coloring exists, or None if it does not.
+    #[allow(dead_code)]
+    pub fn is_k_colorable(&self, k: usize) -> Option<Map<usize, usize>> {
+        let mut colors: Vec<Option<usize>> = vec![None; self.nodes.len()];
+
+        // If no coloring could be found, return None.
+        if !self.color_rec(0, k, &mut colors) {
+            return None;
+        }
+
+        // Build the map of node keys to colors.
+        let mut map: Map<usize, usize> = Map::new();
+
+        for (i, color) in colors.iter().enumerate() {
+            map.insert(kv!(i, color.unwrap()));
+        }
+
+        Some(map)
+    }
+}
+
+// Graph functions for weighted Graph
+impl<V, const DIRECTED: bool> Graph<V, DIRECTED, true>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns each 'node' adjacent to the 'node' with the specified key paired with the weight
+    /// of the 'edge' to it, in key order, or None if no such 'node' exists. For directed 'graphs',
+    /// only 'edges' leaving the 'node' are included.
+    #[allow(dead_code)]
+    pub fn neighbors_weighted(&self, key: usize) -> Option<Vec<(usize, f32)>> {
+        // If the node does not exist, return None.
+        if key >= self.nodes.len() {
+            return None;
+        }
+
+        let mut vec: Vec<(usize, f32)> = Vec::new();
+
+        // Add each node with an edge from the specified node.
+        for j in 0..self.amtx.columns() {
+            if self.amtx[(key, j)] != 0.0 {
+                vec.push((j, self.amtx[(key, j)]));
+            }
+        }
+
+        Some(vec)
+    }
+
+    /// Sets the weight of the existing 'edge' from the 'node' with the first specified key to the
+    /// 'node' with the second specified key. Returns true if successful. Returns false if either
+    /// 'node' does not exist or there is no 'edge' between them. For undirected 'graphs', the
+    /// weight of the 'edge' in both directions is updated. Setting a weight of 0.0 removes the
+    /// 'edge'.
+    #[allow(dead_code)]
+    pub fn set_weight(&mut self, a: usize, b: usize, weight: f32) -> bool {
+        // If either node does not exist, return false.
+        if a >= self.nodes.len() || b >= self.nodes.len() {
+            return false;
+        }
+
+        // If there is no edge from a to b, return false.
+        if self.amtx[(a, b)] == 0.0 {
+            return false;
+        }
+
+        self.amtx[(a, b)] = weight;
+
+        // If this graph is undirected, also update the edge from b to a.
+        if !DIRECTED {
+            self.amtx[(b, a)] = weight;
+        }
+
+        true
+    }
+}
+
+// Graph functions for weighted undirected Graph
+impl<V> Graph<V, false, true>
+    where
+        V: Clone + Debug + PartialEq + PartialOrd,
+{
+    /// Returns the weight of the minimum cut of this 'graph' and the two sets of 'node' keys it
+    /// partitions this 'graph' into, using the Stoer-Wagner algorithm. The minimum cut is the
+    /// set of 'edges' with the smallest total weight whose removal disconnects this 'graph'.
+    /// Returns None if this 'graph' has fewer than two 'nodes'.
+    #[allow(dead_code)]
+    pub fn min_cut(&self) -> Option<GraphCut> {
+        let n: usize = self.nodes.len();
+
+        // If there are fewer than two nodes, return None.
+        if n < 2 {
+            return None;
+        }
+
+        // Copy the edge weights, and start with every node in its own group.
+        let mut weights: Vec<Vec<f32>> = Vec::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut merged: Vec<bool> = vec![false; n];
+
+        for i in 0..n {
+            let mut row: Vec<f32> = Vec::new();
+
+            for j in 0..n {
+                row.push(if i == j { 0.0 } else { self.amtx[(i, j)] });
+            }
+
+            weights.push(row);
+            groups.push(vec![i]);
+        }
+
+        let mut best_weight: f32 = f32::INFINITY;
+        let mut best_group: Vec<usize> = Vec::new();
+
+        // Each phase merges the last two nodes added, so n - 1 phases leave a single node.
+        for phase in 0..(n - 1) {
+            let mut added: Vec<bool> = vec![false; n];
+            let mut conn: Vec<f32> = vec![0.0; n];
+            let mut prev: usize = 0;
+
+            for step in 0..(n - phase) {
+                // Select the most tightly connected node that has not been added yet.
+                let mut sel: Option<usize> = None;
+
+                for j in 0..n {
+                    if !merged[j] && !added[j] && (sel.is_none() || conn[j] > conn[sel.unwrap()]) {
+                        sel = Some(j);
+                    }
+                }
+
+                let s: usize = sel.unwrap();
+                added[s] = true;
+
+                // If this is the last node added, its connection weight is the cut of the phase,
+                // so record it and merge the node into the previous node.
+                if step == n - phase - 1 {
+                    if conn[s] < best_weight {
+                        best_weight = conn[s];
+                        best_group = groups[s].clone();
+                    }
+
+                    let group: Vec<usize> = groups[s].clone();
+                    groups[prev].extend(group);
+                    let row: Vec<f32> = weights[s].clone();
+
+                    for (j, w) in row.iter().enumerate() {
+                        if j != prev {
+                            weights[prev][j] += w;
+                            weights[j][prev] = weights[prev][j];
+                        }
+                    }
+
+                    merged[s] = true;
+                }
+                // Otherwise, add the node's edge weights to the connection weights.
+                else {
+                    for (j, c) in conn.iter_mut().enumerate() {
+                        *c += weights[s][j];
+                    }
+
+                    prev = s;
+                }
+            }
+        }
+
+        // Split the nodes into sets by whether they are in the best group.
+        let mut set_a: Set<usize> = Set::new();
+        let mut set_b: Set<usize> = Set::new();
+
+        for i in 0..n {
+            if best_group.contains(&i) {
+                set_a.add(i);
+            }
+            else {
+                set_b.add(i);
+            }
+        }
+
+        Some((best_weight, (set_a, set_b)))
+    }
 }
\ No newline at end of file
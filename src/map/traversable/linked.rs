@@ -6,12 +6,13 @@
 //! linked to the next element in the list.
 
 use core::fmt::{Debug, Formatter};
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 use crate::collection::{Collection, Reversible};
 use len_trait::{Clear, Empty, Len};
 use crate::kv;
 use crate::map::{KeyValue, MapCollection};
 use crate::map::traversable::*;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a 'linked collection'.
 pub trait LinkedCollection<K, V>: TraversableCollection<K, V>
@@ -299,7 +300,8 @@ impl<V> Reversible for LinkedList<V>
     where
         V: Clone + Debug + PartialEq + PartialOrd,
 {
-    /// Returns a copy of this 'linked list' in reverse order.
+    /// Returns a copy of this 'linked list' in reverse order. This 'linked list' is not changed.
+    /// Use 'reverse_in_place' to reverse this 'linked list' itself.
     fn reverse(&mut self) -> Self {
         let mut rev: LinkedList<V> = LinkedList::new();
 
@@ -529,7 +531,7 @@ impl<V> TraversableCollection<usize, V> for LinkedList<V>
     /// second specified key. Returns None if there is no path. The path contains the key/value
     /// pairs of each 'node' in the path and is stored in order from key_a at the start to
     /// key_b at the end.
-    fn path_of(&mut self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+    fn path_of(&self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
         // If key_a and key_b are valid.
         if key_a < self.nodes.len() && key_b < self.nodes.len() {
             let mut path: DoublyLinkedList<KeyValue<usize, V>> = DoublyLinkedList::new();
@@ -660,6 +662,63 @@ impl<V> LinkedList<V>
 
         list
     }
+
+    /// Reverses the order of the 'nodes' in this 'linked list', meaning the last 'node' becomes
+    /// the head. The keys are renumbered from 0 at the new head, so the key of each 'node' is
+    /// still its position in this 'linked list', and the links are updated to match.
+    #[allow(dead_code)]
+    pub fn reverse_in_place(&mut self) {
+        self.nodes.reverse();
+
+        // Update keys and links for all nodes.
+        for i in 0..self.len() {
+            // If it's not the last node, set link to the next node.
+            if i < self.len() - 1 {
+                self.nodes[i].links[0] = Some(i + 1);
+            }
+            // If it's the last node.
+            else {
+                // If the linked list is circular, set link to the first node.
+                if self.is_circular() {
+                    self.nodes[i].links[0] = Some(0);
+                }
+                // If the linked list is not circular, set link to None.
+                else {
+                    self.nodes[i].links[0] = None;
+                }
+            }
+
+            // Set the key for each node to the current index value (i).
+            self.nodes[i].pair.key = i;
+        }
+    }
+
+    /// Returns a copy of this 'linked list' with its 'nodes' in reverse order, with keys
+    /// renumbered from 0 at the new head. This 'linked list' is not changed.
+    #[allow(dead_code)]
+    pub fn reversed(&self) -> Self {
+        let mut rev: LinkedList<V> = self.clone();
+
+        rev.reverse_in_place();
+
+        rev
+    }
+
+    /// Swaps the values of the 'nodes' with the specified keys. The links between 'nodes' are not
+    /// changed. Returns true if successful. Returns false if either key does not exist.
+    #[allow(dead_code)]
+    pub fn swap(&mut self, key_a: usize, key_b: usize) -> bool {
+        // If either key is out-of-bounds, return false.
+        if key_a >= self.nodes.len() || key_b >= self.nodes.len() {
+            return false;
+        }
+
+        let temp: V = self.nodes[key_a].pair.value.clone();
+        self.nodes[key_a].pair.value = self.nodes[key_b].pair.value.clone();
+        self.nodes[key_b].pair.value = temp;
+
+        true
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1237,7 +1296,7 @@ impl<V> TraversableCollection<usize, V> for DoublyLinkedList<V>
     /// second specified key. Returns None if there is no path. The path contains the key/value
     /// pairs of each 'node' in the path and is stored in order from key_a at the start to
     /// key_b at the end.
-    fn path_of(&mut self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+    fn path_of(&self, key_a: usize, key_b: usize) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
         // If key_a and key_b are valid.
         if key_a < self.nodes.len() && key_b < self.nodes.len() {
             let mut path: DoublyLinkedList<KeyValue<usize, V>> = DoublyLinkedList::new();
@@ -1362,15 +1421,38 @@ impl<V> DoublyLinkedList<V>
 
     /// Creates a new 'doubly linked list' that contains the elements in the specified vector.
     #[allow(dead_code)]
-    pub fn from_vec(v: &Vec<V>) -> Self {
+    pub fn from_vec(v: &[V]) -> Self {
         let mut list: DoublyLinkedList<V> = DoublyLinkedList::new();
-        let mut index: usize = 0;
-
-        for i in v.into_iter() {
-            list.insert(kv!(index, (i.clone())));
-            index += 1;
+        let len: usize = v.len();
+
+        // Add a node for each element, linking it to the next and previous nodes, so the links
+        // do not need to be updated after each insert.
+        for (i, item) in v.iter().enumerate() {
+            let next: Option<usize> = if i + 1 < len { Some(i + 1) } else { None };
+            let prev: Option<usize> = if i > 0 { Some(i - 1) } else { None };
+
+            list.nodes.push(Node {
+                pair: kv!(i, item.clone()),
+                links: vec![next, prev],
+            });
         }
 
         list
     }
+
+    /// Swaps the values of the 'nodes' with the specified keys. The links between 'nodes' are not
+    /// changed. Returns true if successful. Returns false if either key does not exist.
+    #[allow(dead_code)]
+    pub fn swap(&mut self, key_a: usize, key_b: usize) -> bool {
+        // If either key is out-of-bounds, return false.
+        if key_a >= self.nodes.len() || key_b >= self.nodes.len() {
+            return false;
+        }
+
+        let temp: V = self.nodes[key_a].pair.value.clone();
+        self.nodes[key_a].pair.value = self.nodes[key_b].pair.value.clone();
+        self.nodes[key_b].pair.value = temp;
+
+        true
+    }
 }
\ No newline at end of file
@@ -6,16 +6,82 @@
 //! linked together in a tree shaped structure that starts at the top with the root 'node', and
 //! continues downward through child 'nodes' until the 'tree' ends at the leaf 'nodes'.
 
-use core::fmt::{Debug, Formatter};
-use std::cmp::max;
-use std::hash::Hash;
-use std::ops::{Index, IndexMut};
+use core::fmt::{Debug, Display, Formatter};
+use core::cmp::max;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::ops::{Index, IndexMut};
+use core::str::FromStr;
 use crate::collection::Collection;
 use len_trait::{Clear, Empty, Len};
+use crate::map::traversable::graph::{Graph, UUGraph};
 use crate::map::traversable::linked::*;
 use crate::map::*;
 use crate::map::traversable::*;
 use crate::queue::{Queue, QueueCollection};
+use crate::stack::{Stack, StackCollection};
+use crate::prelude::*;
+
+/// Contains the traversal modes used to flatten a 'tree collection' into a list.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TraversalMode {
+    Inorder,
+    LevelOrder,
+    Postorder,
+    Preorder,
+}
+
+/// Contains the errors that can occur when parsing a 'tree' from a string. Each error holds the
+/// index in the string where parsing failed, if there is one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ParseError {
+    /// A key appears more than once in the string.
+    DuplicateKey(usize),
+    /// A key could not be parsed into the key type.
+    InvalidKey(usize),
+    /// A character was found where it is not allowed.
+    UnexpectedChar(usize),
+    /// The string ended before the 'tree' was complete.
+    UnexpectedEnd,
+}
+
+// Display function for ParseError
+impl Display for ParseError {
+    /// Displays a description of this 'parse error'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseError::DuplicateKey(i) => write!(f, "duplicate key at index {}", i),
+            ParseError::InvalidKey(i) => write!(f, "invalid key at index {}", i),
+            ParseError::UnexpectedChar(i) => write!(f, "unexpected character at index {}", i),
+            ParseError::UnexpectedEnd => write!(f, "unexpected end of string"),
+        }
+    }
+}
+
+// Error function for ParseError
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Contains the errors that can occur when building a 'tree' from its deserialized fields.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TreeError {
+    /// The root 'node' and the links between 'nodes' do not form a valid 'tree'.
+    InvalidLinks,
+}
+
+// Display function for TreeError
+impl Display for TreeError {
+    /// Displays a description of this 'tree error'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TreeError::InvalidLinks => write!(f, "nodes are not linked as a valid tree"),
+        }
+    }
+}
+
+// Error function for TreeError
+#[cfg(feature = "std")]
+impl std::error::Error for TreeError {}
 
 // A trait for 'collections' that can implement a 'tree collection'.
 pub trait TreeCollection<K, V>: TraversableCollection<K, V>
@@ -44,6 +110,10 @@ pub trait TreeCollection<K, V>: TraversableCollection<K, V>
     /// no such 'node' with that key exists.
     fn height_from(&self, key: &K) -> isize;
 
+    /// Returns the number of internal 'nodes' in this 'tree'. An internal 'node' is a 'node' that
+    /// has at least one child 'node'.
+    fn internal_count(&self) -> usize;
+
     /// Returns true if the 'node' with the second specified key is an ancestor of the 'node' with
     /// the first specified key. If either key does not belong to an existing 'node', or the two
     /// 'nodes' are not ancestors, this returns false. An ancestor of a 'node' is a 'node' that
@@ -73,6 +143,10 @@ pub trait TreeCollection<K, V>: TraversableCollection<K, V>
     /// 'node'. This is the same as the depth of a 'node'.
     fn level_of(&self, key: &K) -> isize;
 
+    /// Returns the values of the 'nodes' in this 'tree' grouped by level, with the root 'node'
+    /// at index 0. An empty 'tree' returns an empty vector.
+    fn levels(&self) -> Vec<Vec<V>>;
+
     /// Returns the parent 'node' value of the 'node' with the specified key. If no such 'node'
     /// exists or if the 'node' has no parent, this returns None.
     fn parent_node(&self, key: &K) -> Option<&V>;
@@ -88,6 +162,15 @@ pub trait TreeCollection<K, V>: TraversableCollection<K, V>
     /// This function panics if no such 'node' with the specified key exists.
     fn set_node(&mut self, pair: KeyValue<K, V>) -> V;
 
+    /// Returns an indented text diagram of this 'tree', with one line per 'node' showing its key
+    /// and value. Child 'nodes' are drawn below their parent using branch characters. An empty
+    /// 'tree' returns an empty string.
+    fn to_ascii(&self) -> String;
+
+    /// Returns a 'doubly linked list' containing the key value pairs of this 'tree' in the order
+    /// of the specified 'traversal mode'.
+    fn to_list(&self, mode: TraversalMode) -> DoublyLinkedList<KeyValue<K, V>>;
+
     /// Returns the width of the specified level of this 'tree'. This returns 0 if the specified
     /// level does not exist in this 'tree'. The width of a level is the number of 'nodes' in that
     /// level.
@@ -98,7 +181,7 @@ pub trait TreeCollection<K, V>: TraversableCollection<K, V>
 // Tree
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// Contains the traversal modes used by 'trees'.
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum TreeTraversalMode {
     Inorder,
     LevelOrder,
@@ -117,8 +200,11 @@ pub struct TreeTraverser<K, V>
     /// The traverser of a 'doubly linked list' of 'nodes' to traverse stored in the order of the
     /// current 'tree traversal mode' this 'tree traverser' is using.
     trav: DoublyLinkedListTraverser<V>,
-    /// The 'tree' that is being traversed.
-    tree: Tree<K, V>,
+    /// The 'doubly linked lists' of 'nodes' in the order of each 'tree traversal mode', indexed
+    /// by the traversal mode.
+    orders: [DoublyLinkedList<V>; 4],
+    /// Marker for the key type of the 'nodes' being traversed.
+    keys: PhantomData<K>,
 }
 
 // Traverser functions for TreeTraverser
@@ -167,73 +253,25 @@ impl<K, V> TreeCollectionTraverser<K> for TreeTraverser<K, V>
     /// Sets the 'tree traversal mode' of this 'tree collection traverser' to follow inorder
     /// traversal. This is the default 'tree traversal mode'.
     fn inorder(&mut self) {
-        if self.mode != TreeTraversalMode::Inorder {
-            self.mode = TreeTraversalMode::Inorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive inorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.inorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(TreeTraversalMode::Inorder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverse' to follow level order
     /// traversal.
     fn level_order(&mut self) {
-        if self.mode != TreeTraversalMode::LevelOrder {
-            self.mode = TreeTraversalMode::LevelOrder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive level order traversal to populate order.
-            if self.tree.root.is_some() {
-                self.level_order_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(TreeTraversalMode::LevelOrder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverser' to follow postorder
     /// traversal.
     fn postorder(&mut self) {
-        if self.mode != TreeTraversalMode::Postorder {
-            self.mode = TreeTraversalMode::Postorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive postorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.postorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(TreeTraversalMode::Postorder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverser' to follow preorder
     /// traversal.
     fn preorder(&mut self) {
-        if self.mode != TreeTraversalMode::Preorder {
-            self.mode = TreeTraversalMode::Preorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive preorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.preorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(TreeTraversalMode::Preorder);
     }
 }
 
@@ -249,136 +287,190 @@ impl<K, V> TreeTraverser<K, V>
         TreeTraverser {
             mode: TreeTraversalMode::Inorder,
             trav: DoublyLinkedListTraverser::new(),
-            tree: Tree::new(),
+            orders: [
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+            ],
+            keys: PhantomData,
         }
     }
 
-    /// Perform recursive inorder tree traversal to set the order of this 'tree traverser'.
-    fn inorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Creates a new 'tree traverser' in inorder traversal mode from the specified 'tree'.
+    /// Only the order of the 'tree's' values in each traversal mode is stored, so the 'tree'
+    /// itself is not copied.
+    fn from_tree(tree: &Tree<K, V>) -> Self {
+        let mut orders: [Vec<V>; 4] = Default::default();
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
+        // If the tree has a root node, populate the order of each traversal mode.
+        if let Some(r) = &tree.root {
+            let root: K = r.pair.key.clone();
+
+            Self::inorder_iter(tree, &mut orders[TreeTraversalMode::Inorder as usize],
+                               root.clone());
+            Self::level_order_iter(tree, &mut orders[TreeTraversalMode::LevelOrder as usize],
+                                   root.clone());
+            Self::postorder_iter(tree, &mut orders[TreeTraversalMode::Postorder as usize],
+                                 root.clone());
+            Self::preorder_iter(tree, &mut orders[TreeTraversalMode::Preorder as usize],
+                                root.clone());
         }
 
-        // Track the number of indices with keys less than the current node's key.
-        let mut split: usize = 1;
+        // Convert each order into a 'doubly linked list'.
+        let orders: [DoublyLinkedList<V>; 4] = orders.map(|o| DoublyLinkedList::from_vec(&o));
 
-        // For all child nodes with key values less that the current node's key value.
-        while split < curr.links.len() && curr.links[split].is_some() &&
-            curr.links[split].clone().unwrap() < curr.pair.key {
-            // Perform recursive inorder traversal of the child nodes.
-            self.inorder_rec(order, curr.links[split].clone().unwrap().clone());
-            // Increment split index.
-            split += 1;
+        TreeTraverser {
+            mode: TreeTraversalMode::Inorder,
+            trav: orders[TreeTraversalMode::Inorder as usize].clone().into_trav(),
+            orders,
+            keys: PhantomData,
         }
+    }
 
-        // Append the current node's data to order.
-        order.append(curr.pair.value.clone());
-
-        // For all child nodes with key values greater than the current node's key value.
-        for i in split..curr.links.len() {
-            if curr.links[i].is_some() {
-                // Perform recursive inorder traversal of the child nodes.
-                self.inorder_rec(order, curr.links[i].clone().unwrap().clone());
-            }
+    /// Sets the traversal mode of this 'tree traverser' to the specified mode, restarting
+    /// the traversal from the start of the order of that mode.
+    fn set_mode(&mut self, mode: TreeTraversalMode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.trav = self.orders[mode as usize].clone().into_trav();
         }
     }
 
-    /// Perform recursive level order tree traversal to set the order of this 'tree traverser'.
-    fn level_order_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Retrieve the height of the tree.
-        let height: isize = self.tree.height() + 1;
+    /// Perform iterative inorder tree traversal to set the order of a 'tree traverser'.
+    fn inorder_iter(tree: &Tree<K, V>, order: &mut Vec<V>, node: K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(tree.len());
+        stack.push((node, false));
 
-        // For each level, perform recursive level traversal to populate order.
-        for i in 0..height {
-            self.level_order_trav(order, node.clone(), i);
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
+
+            // If the node's children have already been pushed, append the node's data to order.
+            if expanded {
+                order.push(curr.pair.value.clone());
+                continue;
+            }
+
+            // Track the number of indices with keys less than the current node's key.
+            let mut split: usize = 1;
+
+            while split < curr.links.len() && curr.links[split].is_some() &&
+                *curr.links[split].as_ref().unwrap() < curr.pair.key {
+                split += 1;
+            }
+
+            // Push the child nodes with greater keys, the current node, and then the child nodes
+            // with lesser keys in reverse, so they are popped in inorder.
+            for i in (split..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push((child.clone(), false));
+                }
+            }
+
+            stack.push((key.clone(), true));
+
+            for i in (1..split).rev() {
+                stack.push((curr.links[i].clone().unwrap(), false));
+            }
         }
     }
 
-    /// Helper function for recursively performing level order traversal.
-    fn level_order_trav(&mut self, order: &mut DoublyLinkedList<V>, node: K, level: isize) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Perform iterative level order tree traversal to set the order of a 'tree
+    /// traverser'.
+    fn level_order_iter(tree: &Tree<K, V>, order: &mut Vec<V>, node: K) {
+        let mut queue: Queue<K> = Queue::with_capacity(tree.len());
+        queue.enqueue(node);
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+        while let Some(key) = queue.dequeue() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        // If level is 0, append the current node's data to order.
-        if level == 0 {
-            order.append(curr.pair.value.clone());
-        }
-        // If level is not 0.
-        else {
-            // For all child nodes, perform recursive level order traversal with decrement level value.
+            // Append current node's data to order.
+            order.push(curr.pair.value.clone());
+
+            // Enqueue the child nodes, so they are dequeued after the rest of the current level.
             for i in 1..curr.links.len() {
-                if curr.links[i].is_some() {
-                    self.level_order_trav(order, curr.links[i].clone().unwrap().clone(), level - 1);
+                if let Some(child) = &curr.links[i] {
+                    queue.enqueue(child.clone());
                 }
             }
         }
     }
 
-    /// Perform recursive postorder tree traversal to set the order of this 'tree traverser'.
-    fn postorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Perform iterative postorder tree traversal to set the order of a 'tree traverser'.
+    fn postorder_iter(tree: &Tree<K, V>, order: &mut Vec<V>, node: K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(tree.len());
+        stack.push((node, false));
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        // For all child nodes, perform recursive postorder traversal to populate order.
-        for i in 1..curr.links.len() {
-            if curr.links[i].is_some() {
-                self.postorder_rec(order, curr.links[i].clone().unwrap().clone());
+            // If the node's children have already been pushed, append the node's data to order.
+            if expanded {
+                order.push(curr.pair.value.clone());
+                continue;
             }
-        }
 
-        // Append current node's data to order.
-        order.append(curr.pair.value.clone());
+            // Push the current node and then its child nodes in reverse, so the child nodes are
+            // popped first.
+            stack.push((key.clone(), true));
+
+            for i in (1..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push((child.clone(), false));
+                }
+            }
+        }
     }
 
-    /// Recursively traverses this 'tree' via preorder traversal to create the 'tree traverser'.
-    fn preorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Perform iterative preorder tree traversal to set the order of a 'tree traverser'.
+    fn preorder_iter(tree: &Tree<K, V>, order: &mut Vec<V>, node: K) {
+        let mut stack: Stack<K> = Stack::with_capacity(tree.len());
+        stack.push(node);
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+        while let Some(key) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        // Append current node's data to order.
-        order.append(curr.pair.value.clone());
+            // Append current node's data to order.
+            order.push(curr.pair.value.clone());
 
-        // For all child nodes, perform recursive preorder traversal to populate order.
-        for i in 1..curr.links.len() {
-            if curr.links[i].is_some() {
-                self.preorder_rec(order, curr.links[i].clone().unwrap().clone());
+            // Push the child nodes in reverse, so they are popped in order.
+            for i in (1..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push(child.clone());
+                }
             }
         }
     }
 }
 
 /// Contains a list of 'nodes' organized in a tree shaped structure.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TreeParts<K, V>"))]
 pub struct Tree<K, V>
     where
         K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
         V: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Cached depth of each node and the height of the tree, set by 'freeze' and cleared when
+    /// nodes are inserted or removed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    frozen: Option<(HashMap<K, isize>, isize)>,
+    /// Hash map of nodes.
+    nodes: HashMap<K, Node<K, V>>,
+    /// Root node.
+    root: Option<Node<K, V>>,
+}
+
+/// Contains the deserialized fields of a 'tree' before the root 'node' and the links between
+/// 'nodes' are checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct TreeParts<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
+        V: PartialEq + PartialOrd + Clone + Debug,
 {
     /// Hash map of nodes.
     nodes: HashMap<K, Node<K, V>>,
@@ -386,6 +478,30 @@ pub struct Tree<K, V>
     root: Option<Node<K, V>>,
 }
 
+// TryFrom function for Tree
+#[cfg(feature = "serde")]
+impl<K, V> TryFrom<TreeParts<K, V>> for Tree<K, V>
+    where
+        K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
+        V: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Error type.
+    type Error = TreeError;
+
+    /// Creates a 'tree' from its deserialized fields. Returns a 'tree error' if the fields do not
+    /// form a valid 'tree'.
+    fn try_from(parts: TreeParts<K, V>) -> Result<Self, Self::Error> {
+        let tree: Tree<K, V> = Tree { frozen: None, nodes: parts.nodes, root: parts.root };
+
+        // If the root node and the links between nodes do not agree, return an error.
+        if !tree.is_valid_tree() {
+            return Err(TreeError::InvalidLinks);
+        }
+
+        Ok(tree)
+    }
+}
+
 // Clear function for Tree
 impl<K, V> Clear for Tree<K, V>
     where
@@ -394,6 +510,7 @@ impl<K, V> Clear for Tree<K, V>
 {
     /// Clears all the 'nodes' from this 'tree'.
     fn clear(&mut self) {
+        self.frozen = None;
         self.root = None;
         self.nodes.clear()
     }
@@ -408,6 +525,7 @@ impl<K, V> Clone for Tree<K, V>
     /// Returns a clone of this 'tree'.
     fn clone(&self) -> Self {
         Tree {
+            frozen: self.frozen.clone(),
             nodes: self.nodes.clone(),
             root: self.root.clone(),
         }
@@ -423,6 +541,7 @@ impl<K, V> Debug for Tree<K, V>
     /// Displays the debug information for this 'tree'.
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Tree")
+            .field("frozen", &self.frozen)
             .field("nodes", &self.nodes)
             .field("root", &self.root)
             .finish()
@@ -528,7 +647,7 @@ impl<K, V> IntoIterator for Tree<K, V>
             return vec.into_iter();
         }
 
-        let mut trav = self.clone().into_trav();
+        let mut trav = self.trav();
 
         // Traverse the tree inorder.
         while trav.has_next() {
@@ -565,23 +684,7 @@ impl<K, V> IntoTraverser<K> for Tree<K, V>
 
     /// Converts this 'tree' into a 'traverser'.
     fn into_trav(self) -> Self::IntoTrav {
-        let mut t: TreeTraverser<K, V> = TreeTraverser {
-            mode: TreeTraversalMode::Inorder,
-            trav: DoublyLinkedListTraverser::new(),
-            tree: self.clone(),
-        };
-
-        // Traverse the tree inorder and store the order of the nodes.
-        let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-        if self.root.is_some() {
-            t.inorder_rec(&mut order, self.root.unwrap().pair.key.clone());
-        }
-
-        // Set trav to the order converted into a traverser.
-        t.trav = order.clone().into_trav();
-
-        t
+        TreeTraverser::from_tree(&self)
     }
 }
 
@@ -605,8 +708,8 @@ impl<K, V> PartialEq for Tree<K, V>
     /// same 'nodes' in the same order with the same values.
     fn eq(&self, other: &Self) -> bool {
         // Convert both trees into traversers.
-        let mut trav1 = self.clone().into_trav();
-        let mut trav2 = other.clone().into_trav();
+        let mut trav1 = self.trav();
+        let mut trav2 = other.trav();
 
         // If lengths do not match, return false.
         if self.len() != other.len() {
@@ -652,21 +755,12 @@ impl<K, V> Collection for Tree<K, V>
             return false;
         }
 
-        // If item matches the root node, return true.
-        if self.root.clone().unwrap().pair == *item {
-            return true;
+        // Look up the node by the item's key rather than cloning and scanning every node, since
+        // keys are unique. Return true if the node's pair matches item.
+        match self.get_node(&item.key) {
+            Some(node) => node.pair == *item,
+            None => false,
         }
-
-        // If the item matches any node in the tree, return true.
-        let vec = self.nodes.clone().to_vec();
-        for i in 0..vec.len() {
-            if vec[i].value.pair == *item {
-                return true;
-            }
-        }
-
-        // If item does not match a node in the tree, return false.
-        false
     }
 
     /// Returns true if this 'tree' contains the specified vector.
@@ -690,22 +784,9 @@ impl<K, V> Collection for Tree<K, V>
             return vec;
         }
 
-        let mut trav = self.clone().into_trav();
-
-        // Traverse the tree and add all nodes to the vector following inorder traversal.
-        while trav.has_next() {
-            let data: V = trav.next().unwrap().clone();
-
-            if data == self.root.clone().unwrap().pair.value {
-                vec.push(self.root.clone().unwrap().pair.clone());
-            }
-
-            for i in self.nodes.clone().into_iter() {
-                if i.value.pair.value == data {
-                    vec.push(i.value.pair.clone());
-                }
-            }
-        }
+        // Walk the borrowed nodes following inorder traversal instead of cloning this tree into a
+        // traverser and scanning every node for each traversed value.
+        self.inorder_pairs(&mut vec, &self.root.as_ref().unwrap().pair.key);
 
         vec
     }
@@ -761,6 +842,8 @@ impl<K, V> MapCollection<K, V> for Tree<K, V>
             return false;
         }
 
+        self.frozen = None;
+
         match &mut self.root {
             // If there is a root node, add the new node as a child of the root node.
             Some(r) => {
@@ -793,8 +876,10 @@ impl<K, V> MapCollection<K, V> for Tree<K, V>
             return false;
         }
 
+        self.frozen = None;
+
         // Create a queue that starts with the specified node key.
-        let mut queue: Queue<K> = Queue::new();
+        let mut queue: Queue<K> = Queue::with_capacity(self.len());
         queue.enqueue(key.clone());
 
         // Perform iterative inorder traversal of the tree.
@@ -1051,7 +1136,7 @@ impl<K, V> TraversableCollection<K, V> for Tree<K, V>
     /// key_b at the end. For a 'tree', this retrieves key_a's subtree and, if key_b is in that
     /// subtree, key_b's parent and its parents are followed up to the root, which is key_a and
     /// stores these nodes in reverse order to get the path from key_a to key_b, if it exists.
-    fn path_of(&mut self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+    fn path_of(&self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
         // If key_a and key_b are valid.
         if self.exists(key_a.clone()) && self.exists(key_b.clone()) {
             let mut path: DoublyLinkedList<KeyValue<usize, V>> = DoublyLinkedList::new();
@@ -1198,6 +1283,14 @@ impl<K, V> TreeCollection<K, V> for Tree<K, V>
     /// that key exists. The depth of a 'node' is the number of edges it has from the root 'node'.
     /// This is the same as the level of a 'node'.
     fn depth_of(&self, key: &K) -> isize {
+        // If this tree is frozen, return the cached depth, or -1 if key did not match any nodes.
+        if let Some((depths, _)) = &self.frozen {
+            return match depths.get(key.clone()) {
+                Some(depth) => *depth,
+                None => -1,
+            };
+        }
+
         // If there is no root node (aka no tree), return -1.
         if self.root.is_none() {
             return -1;
@@ -1237,6 +1330,11 @@ impl<K, V> TreeCollection<K, V> for Tree<K, V>
     /// Returns the height of this 'tree'. The height of a 'tree' is the distance from the root
     /// 'node' to the leaf 'node' that is furthest away.
     fn height(&self) -> isize {
+        // If this tree is frozen, return the cached height.
+        if let Some((_, height)) = &self.frozen {
+            return *height;
+        }
+
         // If there is no root node (aka no tree), return -1.
         if self.root.is_none() {
             return -1;
@@ -1338,6 +1436,33 @@ impl<K, V> TreeCollection<K, V> for Tree<K, V>
         height
     }
 
+    /// Returns the number of internal 'nodes' in this 'tree'. An internal 'node' is a 'node' that
+    /// has at least one child 'node'.
+    fn internal_count(&self) -> usize {
+        // If there is no root node (aka no tree), return 0.
+        if self.root.is_none() {
+            return 0;
+        }
+
+        let mut count: usize = 0;
+
+        // Count the root node if it has children.
+        if self.root.clone().unwrap().links.len() > 1 {
+            count += 1;
+        }
+
+        // Count every other node that has children.
+        let nodes: Vec<KeyValue<K, Node<K, V>>> = self.nodes.to_vec();
+
+        for node in nodes.iter() {
+            if node.value.links.len() > 1 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
     /// Returns true if the 'node' with the second specified key is an ancestor of the 'node' with
     /// the first specified key. If either key does not belong to an existing 'node', or the two
     /// 'nodes' are not ancestors, this returns false. An ancestor of a 'node' is a 'node' that
@@ -1487,6 +1612,48 @@ impl<K, V> TreeCollection<K, V> for Tree<K, V>
     /// 'node'. This is the same as the depth of a 'node'.
     fn level_of(&self, key: &K) -> isize { self.depth_of(key) }
 
+    /// Returns the values of the 'nodes' in this 'tree' grouped by level, with the root 'node'
+    /// at index 0. An empty 'tree' returns an empty vector.
+    fn levels(&self) -> Vec<Vec<V>> {
+        let mut levels: Vec<Vec<V>> = Vec::new();
+
+        // If there is no root node (aka no tree), return an empty vector.
+        if self.root.is_none() {
+            return levels;
+        }
+
+        let mut queue: Queue<K> = Queue::with_capacity(self.len());
+        queue.enqueue(self.root.clone().unwrap().pair.key.clone());
+
+        // Perform iterative level order traversal.
+        while !queue.is_empty() {
+            // Store the queue's current length.
+            let mut len: usize = queue.len();
+            let mut level: Vec<V> = Vec::with_capacity(len);
+
+            // Go through the current nodes in the queue.
+            while len > 0 {
+                let node: &Node<K, V> = self.get_node(&queue.dequeue().unwrap()).unwrap();
+
+                // Add the node's value to the current level and its children to the queue.
+                level.push(node.pair.value.clone());
+
+                for i in 1..node.links.len() {
+                    if let Some(child) = &node.links[i] {
+                        queue.enqueue(child.clone());
+                    }
+                }
+
+                // Decrement the stored length.
+                len -= 1;
+            }
+
+            levels.push(level);
+        }
+
+        levels
+    }
+
     /// Returns the parent 'node' of the 'node' with the specified key. If no such 'node' exists or
     /// if the 'node' has no parent, this returns None.
     fn parent_node(&self, key: &K) -> Option<&V> {
@@ -1538,6 +1705,77 @@ impl<K, V> TreeCollection<K, V> for Tree<K, V>
         ret
     }
 
+    /// Returns an indented text diagram of this 'tree', with one line per 'node' showing its
+    /// key and value. Child 'nodes' are drawn below their parent using branch characters. An
+    /// empty 'tree' returns an empty string.
+    fn to_ascii(&self) -> String {
+        let mut ascii: String = String::new();
+
+        // If there is no root node (aka no tree), return an empty string.
+        if self.root.is_none() {
+            return ascii;
+        }
+
+        // Each entry holds a node's key, the prefix of its line, and its branch characters.
+        let mut stack: Vec<(K, String, &str)> = Vec::new();
+        stack.push((self.root.clone().unwrap().pair.key.clone(), String::new(), ""));
+
+        // Perform iterative preorder traversal, writing one line per node.
+        while let Some((key, prefix, branch)) = stack.pop() {
+            let node: &Node<K, V> = self.get_node(&key).unwrap();
+
+            ascii.push_str(&format!("{}{}{:?}: {:?}\n", prefix, branch, node.pair.key,
+                node.pair.value));
+
+            // Get the child prefix, continuing the parent's branch line if it has more siblings.
+            let child_prefix: String = match branch {
+                "├── " => format!("{}│   ", prefix),
+                "└── " => format!("{}    ", prefix),
+                _ => prefix,
+            };
+
+            let mut children: Vec<K> = Vec::new();
+
+            for i in 1..node.links.len() {
+                if let Some(child) = &node.links[i] {
+                    children.push(child.clone());
+                }
+            }
+
+            // Push the children in reverse so the first child is written first.
+            for i in (0..children.len()).rev() {
+                let child_branch: &str = if i == children.len() - 1 { "└── " } else { "├── " };
+                stack.push((children[i].clone(), child_prefix.clone(), child_branch));
+            }
+        }
+
+        ascii
+    }
+
+    /// Returns a 'doubly linked list' containing the key value pairs of this 'tree' in the order
+    /// of the specified 'traversal mode'.
+    fn to_list(&self, mode: TraversalMode) -> DoublyLinkedList<KeyValue<K, V>> {
+        // Traverse a copy of this tree with each value replaced by its key to get the key order.
+        let mut trav: TreeTraverser<K, K> = self.map_pairs(|pair| pair.key.clone()).into_trav();
+
+        match mode {
+            TraversalMode::Inorder => trav.inorder(),
+            TraversalMode::LevelOrder => trav.level_order(),
+            TraversalMode::Postorder => trav.postorder(),
+            TraversalMode::Preorder => trav.preorder(),
+        }
+
+        let mut list: DoublyLinkedList<KeyValue<K, V>> = DoublyLinkedList::new();
+
+        // Append each key value pair in traversal order.
+        while trav.has_next() {
+            let key: K = trav.next().unwrap();
+            list.append(KeyValue { key: key.clone(), value: self.get(key).unwrap().clone() });
+        }
+
+        list
+    }
+
     /// Returns the width of the specified level of this 'tree'. This returns 0 if the specified
     /// level does not exist in this 'tree'. The width of a level is the number of 'nodes' in that
     /// level.
@@ -1563,6 +1801,7 @@ impl<K, V> Tree<K, V>
     /// Creates a new empty 'tree'.
     pub fn new() -> Self {
         let new: Tree<K, V> = Tree {
+            frozen: None,
             nodes: HashMap::new(),
             root: None,
         };
@@ -1574,6 +1813,7 @@ impl<K, V> Tree<K, V>
     #[allow(dead_code)]
     pub fn new_root(pair: KeyValue<K, V>) -> Self {
         let mut new: Tree<K, V> = Tree {
+            frozen: None,
             nodes: HashMap::new(),
             root: Some(Node {
                 pair: pair.clone(),
@@ -1603,6 +1843,99 @@ impl<K, V> Tree<K, V>
         tree
     }
 
+    /// Creates a new 'tree' from the specified string in the form produced by 'serialize', such
+    /// as 400(100(10)(20))(200(110)). Each 'node' is given the default value. An empty string
+    /// creates an empty 'tree'. Returns a 'parse error' if the string is not in that form, if a
+    /// key cannot be parsed, or if a key appears more than once.
+    #[allow(dead_code)]
+    pub fn deserialize(s: &str) -> Result<Tree<K, V>, ParseError>
+        where
+            K: FromStr,
+            V: Default,
+    {
+        let bytes: &[u8] = s.as_bytes();
+        let mut tree: Tree<K, V> = Tree::new();
+        let mut parents: Stack<K> = Stack::with_capacity(bytes.len());
+        let mut pos: usize = 0;
+
+        // If the string is empty, return an empty tree.
+        if s.is_empty() {
+            return Ok(tree);
+        }
+
+        loop {
+            let key_start: usize = pos;
+
+            // Read the key up to the start of a child node or the end of this node.
+            while pos < bytes.len() && bytes[pos] != b'(' && bytes[pos] != b')' {
+                pos += 1;
+            }
+
+            // If there is no key, return an error.
+            if pos == bytes.len() && pos == key_start {
+                return Err(ParseError::UnexpectedEnd);
+            }
+
+            if pos == key_start {
+                return Err(ParseError::UnexpectedChar(pos));
+            }
+
+            let mut key: K = s[key_start..pos].parse()
+                .map_err(|_| ParseError::InvalidKey(key_start))?;
+
+            let pair: KeyValue<K, V> = KeyValue { key: key.clone(), value: V::default() };
+
+            // If the key already exists in the tree, return an error.
+            if !tree.insert_at(parents.peek_top().cloned(), pair) {
+                return Err(ParseError::DuplicateKey(key_start));
+            }
+
+            // Close each finished node until a node with another child is found.
+            loop {
+                // If another child node is opened, parse it next.
+                if pos < bytes.len() && bytes[pos] == b'(' {
+                    parents.push(key);
+                    pos += 1;
+                    break;
+                }
+
+                // If the root node is finished, return the tree if nothing is left after it.
+                let parent: K = match parents.pop() {
+                    Some(p) => p,
+                    None if pos == bytes.len() => return Ok(tree),
+                    None => return Err(ParseError::UnexpectedChar(pos)),
+                };
+
+                // If the child node is not closed, return an error.
+                if pos == bytes.len() {
+                    return Err(ParseError::UnexpectedEnd);
+                }
+
+                if bytes[pos] != b')' {
+                    return Err(ParseError::UnexpectedChar(pos));
+                }
+
+                pos += 1;
+                key = parent;
+            }
+        }
+    }
+
+    /// Returns the keys of the 'node' with the specified key and each of its ancestors, in order
+    /// from the 'node' up to the root 'node'.
+    fn ancestors_of(&self, key: &K) -> Vec<K> {
+        let mut keys: Vec<K> = vec![key.clone()];
+        let mut curr: &Node<K, V> = self.get_node(key).unwrap();
+
+        // Add each parent node's key until the root node is reached.
+        while let Some(parent) = &curr.links[0] {
+            keys.push(parent.clone());
+            curr = self.get_node(parent).unwrap();
+        }
+
+        keys
+    }
+
     /// Returns the maximum depth of this 'tree'. This is used to calculate this 'tree's'
     /// diameter.
     fn get_max_depth(&self, node: K, diameter: &mut usize) -> usize {
@@ -1681,6 +2014,57 @@ impl<K, V> Tree<K, V>
         }
     }
 
+    /// Returns the 'node' with the specified key, or None if no such 'node' exists in this
+    /// 'tree'.
+    fn get_node(&self, key: &K) -> Option<&Node<K, V>> {
+        match &self.root {
+            // If key matches the root node, return the root node.
+            Some(r) if r.pair.key == *key => Some(r),
+            Some(_) => self.nodes.get(key.clone()),
+            None => None,
+        }
+    }
+
+    /// Adds the key value pairs of the specified 'node' and its descendants to the specified
+    /// vector following inorder traversal.
+    fn inorder_pairs(&self, vec: &mut Vec<KeyValue<K, V>>, node: &K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(self.len());
+        stack.push((node.clone(), false));
+
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = self.get_node(&key).unwrap();
+
+            // If the node's children have already been pushed, add the node's pair to the vector.
+            if expanded {
+                vec.push(curr.pair.clone());
+                continue;
+            }
+
+            // Track the number of indices with keys less than the current node's key.
+            let mut split: usize = 1;
+
+            while split < curr.links.len() && curr.links[split].is_some() &&
+                *curr.links[split].as_ref().unwrap() < curr.pair.key {
+                split += 1;
+            }
+
+            // Push the child nodes with greater keys, the current node, and then the child nodes
+            // with lesser keys in reverse, so they are popped in inorder.
+            for i in (split..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push((child.clone(), false));
+                }
+            }
+
+            stack.push((key.clone(), true));
+
+            for i in (1..split).rev() {
+                stack.push((curr.links[i].clone().unwrap(), false));
+            }
+        }
+    }
+
     /// Inserts a new 'node' with the specified key and value into this 'tree' as a child of the
     /// 'node' with the specified key position. Returns true if successful. Returns false if the
     /// new key to insert already exists, or if the specified key position is invalid.
@@ -1691,6 +2075,8 @@ impl<K, V> Tree<K, V>
             return false;
         }
 
+        self.frozen = None;
+
         // If no key position is specified.
         if pos.is_none() {
             match &mut self.root {
@@ -1753,13 +2139,277 @@ impl<K, V> Tree<K, V>
         true
     }
 
+    /// Removes the 'node' with the specified key and all of its descendant 'nodes' from this
+    /// 'tree', and returns them as a new 'tree' with the specified 'node' set as the root 'node'.
+    /// Returns None if no such 'node' with that key exists.
+    #[allow(dead_code)]
+    pub fn detach_subtree(&mut self, key: K) -> Option<Tree<K, V>> {
+        // If the node does not exist, return None.
+        if !self.exists(key.clone()) {
+            return None;
+        }
+
+        let mut sub: Tree<K, V> = self.subtree(key.clone());
+
+        // Clear the parent link of the subtree's root node, since it no longer has a parent.
+        if let Some(r) = &mut sub.root {
+            r.links[0] = None;
+        }
+
+        self.remove(key);
+
+        Some(sub)
+    }
+
+    /// Inserts each of the specified key value pairs into this 'tree' as a child of the 'node'
+    /// with the specified parent key. Returns the number of 'nodes' that were inserted. Pairs
+    /// whose key already exists are skipped, and nothing is inserted if the parent key does not
+    /// exist.
+    #[allow(dead_code)]
+    pub fn insert_children(&mut self, parent: K, children: &[KeyValue<K, V>]) -> usize {
+        // If the parent node does not exist, return 0.
+        if !self.exists(parent.clone()) {
+            return 0;
+        }
+
+        let mut count: usize = 0;
+
+        for child in children.iter() {
+            if self.insert_at(Some(parent.clone()), child.clone()) {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Precomputes and caches the depth of every 'node' and the height of this 'tree', so that
+    /// 'depth_of' and 'height' return the cached values instead of traversing this 'tree'. The
+    /// cache is cleared when a 'node' is inserted or removed, or this 'tree' is cleared, after
+    /// which 'freeze' can be called again.
+    #[allow(dead_code)]
+    pub fn freeze(&mut self) {
+        let mut depths: HashMap<K, isize> = HashMap::new();
+        let mut height: isize = -1;
+
+        // If there is a root node, record the depth of every node with a preorder traversal.
+        if let Some(r) = &self.root {
+            let mut stack: Vec<(K, isize)> = vec![(r.pair.key.clone(), 0)];
+
+            while let Some((key, depth)) = stack.pop() {
+                let node: &Node<K, V> = self.get_node(&key).unwrap();
+
+                for i in 1..node.links.len() {
+                    if let Some(child) = &node.links[i] {
+                        stack.push((child.clone(), depth + 1));
+                    }
+                }
+
+                height = max(height, depth);
+                depths.insert(KeyValue { key, value: depth });
+            }
+        }
+
+        self.frozen = Some((depths, height));
+    }
+
+    /// Returns true if this 'tree' has cached 'node' depths from 'freeze' that have not been
+    /// cleared by a later change.
+    #[allow(dead_code)]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_some()
+    }
+
+    /// Returns true if this 'tree' is a valid single rooted 'tree'. This means the root 'node' has
+    /// no parent, every other 'node' is reachable from the root 'node' through exactly one parent,
+    /// and there are no cycles. An empty 'tree' is valid.
+    #[allow(dead_code)]
+    pub fn is_valid_tree(&self) -> bool {
+        // If there is no root node, the tree is only valid if it has no other nodes.
+        let root: &Node<K, V> = match &self.root {
+            Some(r) => r,
+            None => return self.nodes.is_empty(),
+        };
+
+        // If the root node has a parent, return false.
+        if root.links.is_empty() || root.links[0].is_some() {
+            return false;
+        }
+
+        let mut visited: Vec<K> = vec![root.pair.key.clone()];
+        let mut stack: Vec<(K, K)> = Vec::new();
+
+        for i in 1..root.links.len() {
+            if let Some(child) = &root.links[i] {
+                stack.push((root.pair.key.clone(), child.clone()));
+            }
+        }
+
+        // Visit each node reachable from the root node, along with the parent linking to it.
+        while let Some((parent, key)) = stack.pop() {
+            // If the node was already visited (a cycle) or does not exist, return false.
+            if visited.contains(&key) || !self.nodes.exists(key.clone()) {
+                return false;
+            }
+
+            let node: &Node<K, V> = &self.nodes[key.clone()];
+
+            // If the node's parent link does not match the node linking to it, return false.
+            if node.links.is_empty() || node.links[0] != Some(parent) {
+                return false;
+            }
+
+            visited.push(key.clone());
+
+            for i in 1..node.links.len() {
+                if let Some(child) = &node.links[i] {
+                    stack.push((key.clone(), child.clone()));
+                }
+            }
+        }
+
+        // If any node could not be reached from the root node, return false.
+        visited.len() == self.nodes.len() + 1
+    }
+
+    /// Returns a new 'tree' with the same structure and keys as this 'tree', but with each value
+    /// set to the result of calling the specified function on the original key value pair.
+    fn map_pairs<U, F: Fn(&KeyValue<K, V>) -> U>(&self, f: F) -> Tree<K, U>
+        where
+            U: PartialEq + PartialOrd + Clone + Debug,
+    {
+        let mut tree: Tree<K, U> = Tree::new();
+
+        // Map the root node's pair, keeping its links.
+        if let Some(r) = &self.root {
+            tree.root = Some(Node {
+                pair: KeyValue { key: r.pair.key.clone(), value: f(&r.pair) },
+                links: r.links.clone(),
+            });
+        }
+
+        // Map the pair of every other node, keeping its links.
+        let nodes: Vec<KeyValue<K, Node<K, V>>> = self.nodes.to_vec();
+
+        for node in nodes.iter() {
+            tree.nodes.insert(
+                KeyValue {
+                    key: node.key.clone(),
+                    value: Node {
+                        pair: KeyValue {
+                            key: node.value.pair.key.clone(),
+                            value: f(&node.value.pair),
+                        },
+                        links: node.value.links.clone(),
+                    }});
+        }
+
+        tree
+    }
+
+    /// Returns a new 'tree' with the same structure and keys as this 'tree', but with each value
+    /// set to the result of calling the specified function on the original value.
+    #[allow(dead_code)]
+    pub fn map_values<U, F: Fn(&V) -> U>(&self, f: F) -> Tree<K, U>
+        where
+            U: PartialEq + PartialOrd + Clone + Debug,
+    {
+        self.map_pairs(|pair| f(&pair.value))
+    }
+
+    /// Returns a 'doubly linked list' containing the path from the first specified key to the
+    /// second specified key, going up from key_a to the lowest common ancestor of both 'nodes'
+    /// and then down to key_b. Returns None if either key does not belong to an existing 'node'.
+    /// The path contains the position and value of each 'node' in the path and is stored in
+    /// order from key_a at the start to key_b at the end.
+    #[allow(dead_code)]
+    pub fn path_between(&self, key_a: K, key_b: K)
+        -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+        // If key_a or key_b are not valid, return None.
+        if !self.exists(key_a.clone()) || !self.exists(key_b.clone()) {
+            return None;
+        }
+
+        let up: Vec<K> = self.ancestors_of(&key_a);
+        let down: Vec<K> = self.ancestors_of(&key_b);
+
+        // Find the lowest common ancestor by walking up from key_b until a node on key_a's path
+        // to the root is reached.
+        let mut lca: usize = 0;
+
+        while !up.contains(&down[lca]) {
+            lca += 1;
+        }
+
+        let mut path: DoublyLinkedList<KeyValue<usize, V>> = DoublyLinkedList::new();
+        let mut index: usize = 0;
+
+        // Append the nodes from key_a up to and including the lowest common ancestor.
+        for key in up.iter() {
+            let value: V = self.get_node(key).unwrap().pair.value.clone();
+            path.append(KeyValue { key: index, value });
+            index += 1;
+
+            if *key == down[lca] {
+                break;
+            }
+        }
+
+        // Append the nodes from below the lowest common ancestor down to key_b.
+        for i in (0..lca).rev() {
+            let value: V = self.get_node(&down[i]).unwrap().pair.value.clone();
+            path.append(KeyValue { key: index, value });
+            index += 1;
+        }
+
+        Some(path)
+    }
+
+    /// Returns this 'tree' as a string of nested 'nodes', where each 'node' is written as its key
+    /// followed by each of its children in parentheses, such as 400(100(10)(20))(200(110)). Values
+    /// are not written. An empty 'tree' returns an empty string. Use 'deserialize' to create a
+    /// 'tree' from the string. Keys must not display a parenthesis.
+    #[allow(dead_code)]
+    pub fn serialize(&self) -> String
+        where
+            K: Display,
+    {
+        let mut s: String = String::new();
+        let mut stack: Stack<(K, usize)> = Stack::with_capacity(self.len());
+
+        if let Some(r) = &self.root {
+            s.push_str(&format!("{}", r.pair.key));
+            stack.push((r.pair.key.clone(), 1));
+        }
+
+        // Write the next child of the node on top of the stack, or close the node if it has no
+        // children left.
+        while let Some((key, i)) = stack.pop() {
+            let node: &Node<K, V> = self.get_node(&key).unwrap();
+
+            if i < node.links.len() {
+                stack.push((key.clone(), i + 1));
+
+                if let Some(child) = &node.links[i] {
+                    s.push_str(&format!("({}", child));
+                    stack.push((child.clone(), 1));
+                }
+            }
+            else if !stack.is_empty() {
+                s.push(')');
+            }
+        }
+
+        s
+    }
+
     /// Returns a subtree with the specified 'node' in this 'tree' set as the root 'node' in the
     /// returned subtree.
     ///
     /// # Panics
     ///
     /// This function panics if the specified 'node' does not exist in this 'tree'.
-    pub fn subtree(&mut self, node: K) -> Tree<K, V> {
+    pub fn subtree(&self, node: K) -> Tree<K, V> {
         // Panic the the specified node is not in the tree.
         if !self.exists(node.clone()) {
             panic!("Cannot create subtree due to non-existent node specified.");
@@ -1773,7 +2423,7 @@ impl<K, V> Tree<K, V>
         sub
     }
 
-    fn subtree_rec(&mut self, sub: &mut Tree<K, V>, node: K) {
+    fn subtree_rec(&self, sub: &mut Tree<K, V>, node: K) {
         if node == self.root.clone().unwrap().pair.key.clone() {
             if sub.root.is_none() {
                 sub.root = Some(self.root.clone().unwrap().clone());
@@ -1808,13 +2458,53 @@ impl<K, V> Tree<K, V>
             }
         }
     }
+
+    /// Returns an undirected unweighted 'graph' containing a 'node' for each 'node' in this
+    /// 'tree' and an 'edge' between each parent 'node' and each of its child 'nodes'. The 'graph'
+    /// keys are assigned in level order, so the root 'node' has key 0.
+    #[allow(dead_code)]
+    pub fn to_graph(&self) -> UUGraph<V> {
+        let mut graph: UUGraph<V> = Graph::new();
+        let list: Vec<KeyValue<usize, KeyValue<K, V>>> =
+            self.to_list(TraversalMode::LevelOrder).to_vec();
+        let mut indices: HashMap<K, usize> = HashMap::new();
+
+        // Add a graph node for each tree node in level order, remembering its graph key.
+        for (i, item) in list.iter().enumerate() {
+            indices.insert(KeyValue { key: item.value.key.clone(), value: i });
+            graph.insert(KeyValue { key: i, value: item.value.value.clone() });
+        }
+
+        // Connect each node to its child nodes, skipping empty child links.
+        for item in list.iter() {
+            let node: &Node<K, V> = self.get_node(&item.value.key).unwrap();
+
+            for child in node.links.iter().skip(1).flatten() {
+                graph.connect(Edge {
+                    node_a: indices[item.value.key.clone()],
+                    node_b: indices[child.clone()],
+                    weight: 1.0,
+                });
+            }
+        }
+
+        graph
+    }
+
+    /// Returns a 'traverser' for this 'tree' without consuming or copying it. The 'traverser'
+    /// starts in inorder traversal mode and holds only the order of this 'tree's' values in each
+    /// traversal mode.
+    #[allow(dead_code)]
+    pub fn trav(&self) -> TreeTraverser<K, V> {
+        TreeTraverser::from_tree(self)
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // BinaryTree
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// Contains the traversal modes used by 'binary trees'.
-#[derive(PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 enum BinaryTreeTraversalMode {
     Boundary,
     Diagonal,
@@ -1835,8 +2525,11 @@ pub struct BinaryTreeTraverser<K, V, const BALANCED: bool>
     /// The traverser of a 'doubly linked list' of 'nodes' to traverse stored in the order of the
     /// current 'tree traversal mode' this 'tree traverser' is using.
     trav: DoublyLinkedListTraverser<V>,
-    /// The 'binary tree' that is being traversed.
-    tree: BinaryTree<K, V, BALANCED>,
+    /// The 'doubly linked lists' of 'nodes' in the order of each 'binary tree traversal mode',
+    /// indexed by the traversal mode.
+    orders: [DoublyLinkedList<V>; 6],
+    /// Marker for the key type of the 'nodes' being traversed.
+    keys: PhantomData<K>,
 }
 
 // Traverser functions for BinaryTreeTraverser
@@ -1886,73 +2579,25 @@ impl<K, V, const BALANCED: bool> TreeCollectionTraverser<K> for BinaryTreeTraver
     /// Sets the 'binary tree traversal mode' of this 'tree collection traverser' to follow
     /// inorder traversal. This is the default 'tree traversal mode'.
     fn inorder(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::Inorder {
-            self.mode = BinaryTreeTraversalMode::Inorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive inorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.inorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::Inorder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverse' to follow level order
     /// traversal.
     fn level_order(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::LevelOrder {
-            self.mode = BinaryTreeTraversalMode::LevelOrder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive level order traversal to populate order.
-            if self.tree.root.is_some() {
-                self.level_order_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::LevelOrder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverser' to follow postorder
     /// traversal.
     fn postorder(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::Postorder {
-            self.mode = BinaryTreeTraversalMode::Postorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive postorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.postorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::Postorder);
     }
 
     /// Sets the 'tree traversal mode' of this 'tree collection traverser' to follow preorder
     /// traversal.
     fn preorder(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::Preorder {
-            self.mode = BinaryTreeTraversalMode::Preorder;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use recursive preorder traversal to populate order.
-            if self.tree.root.is_some() {
-                self.preorder_rec(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::Preorder);
     }
 }
 
@@ -1965,50 +2610,13 @@ impl<K, V, const BALANCED: bool> BinaryTreeCollectionTraverser<K> for BinaryTree
     /// Sets the 'binary tree traversal mode' of this 'binary tree collection traverser' to
     /// follow boundary traversal.
     fn boundary(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::Boundary {
-            self.mode = BinaryTreeTraversalMode::Boundary;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Add root node to order, then traverse left boundary, leaves, and the right
-            // boundary.
-            if self.tree.root.is_some() {
-                order.append(self.tree.root.clone().unwrap().pair.value.clone());
-                if self.tree.root.clone().unwrap().links[1].is_some() {
-                    self.boundary_left(&mut order,
-                                       self.tree.root.clone().unwrap().links[1].clone().unwrap().clone());
-                    self.boundary_leaves(&mut order,
-                                         self.tree.root.clone().unwrap().links[1].clone().unwrap().clone());
-                }
-                if self.tree.root.clone().unwrap().links[2].is_some() {
-                    self.boundary_leaves(&mut order,
-                                         self.tree.root.clone().unwrap().links[2].clone().unwrap().clone());
-                    self.boundary_right(&mut order,
-                                        self.tree.root.clone().unwrap().links[2].clone().unwrap().clone());
-                }
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::Boundary);
     }
 
     /// Sets the 'binary tree traversal mode' of this 'binary tree collection traverser' to
     /// follow diagonal traversal.
     fn diagonal(&mut self) {
-        if self.mode != BinaryTreeTraversalMode::Diagonal {
-            self.mode = BinaryTreeTraversalMode::Diagonal;
-
-            let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-            // Use iterative diagonal traversal to populate order.
-            if self.tree.root.is_some() {
-                self.diagonal_iter(&mut order, self.tree.root.clone().unwrap().pair.key.clone());
-            }
-
-            // Set trav to order converted into a traverser.
-            self.trav = order.clone().into_trav();
-        }
+        self.set_mode(BinaryTreeTraversalMode::Diagonal);
     }
 }
 
@@ -2024,257 +2632,267 @@ impl<K, V, const BALANCED: bool> BinaryTreeTraverser<K, V, BALANCED>
         BinaryTreeTraverser {
             mode: BinaryTreeTraversalMode::Inorder,
             trav: DoublyLinkedListTraverser::new(),
-            tree: BinaryTree::new(),
+            orders: [
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+                DoublyLinkedList::new(),
+            ],
+            keys: PhantomData,
         }
     }
 
-    /// Perform boundary traversal of the leaf nodes to set the order of this 'binary tree
-    /// traverser'.
-    fn boundary_leaves(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Creates a new 'binary tree traverser' in inorder traversal mode from the specified 'binary
+    /// tree'. Only the order of the 'binary tree's' values in each traversal mode is stored, so
+    /// the 'binary tree' itself is not copied.
+    fn from_tree(tree: &BinaryTree<K, V, BALANCED>) -> Self {
+        let mut orders: [Vec<V>; 6] = Default::default();
+
+        // If the tree has a root node, populate the order of each traversal mode.
+        if let Some(r) = &tree.root {
+            let root: K = r.pair.key.clone();
+
+            Self::boundary_order(tree, &mut orders[BinaryTreeTraversalMode::Boundary as usize],
+                                 root.clone());
+            Self::diagonal_iter(tree, &mut orders[BinaryTreeTraversalMode::Diagonal as usize],
+                                root.clone());
+            Self::inorder_iter(tree, &mut orders[BinaryTreeTraversalMode::Inorder as usize],
+                               root.clone());
+            Self::level_order_iter(tree, &mut orders[BinaryTreeTraversalMode::LevelOrder as usize],
+                                   root.clone());
+            Self::postorder_iter(tree, &mut orders[BinaryTreeTraversalMode::Postorder as usize],
+                                 root.clone());
+            Self::preorder_iter(tree, &mut orders[BinaryTreeTraversalMode::Preorder as usize],
+                                root.clone());
+        }
+
+        // Convert each order into a 'doubly linked list'.
+        let orders: [DoublyLinkedList<V>; 6] = orders.map(|o| DoublyLinkedList::from_vec(&o));
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
+        BinaryTreeTraverser {
+            mode: BinaryTreeTraversalMode::Inorder,
+            trav: orders[BinaryTreeTraversalMode::Inorder as usize].clone().into_trav(),
+            orders,
+            keys: PhantomData,
         }
+    }
 
-        // Recursively traverse left child
-        if curr.links[1].is_some() {
-            self.boundary_leaves(order, curr.links[1].clone().unwrap().clone());
+    /// Sets the traversal mode of this 'binary tree traverser' to the specified mode, restarting
+    /// the traversal from the start of the order of that mode.
+    fn set_mode(&mut self, mode: BinaryTreeTraversalMode) {
+        if self.mode != mode {
+            self.mode = mode;
+            self.trav = self.orders[mode as usize].clone().into_trav();
         }
+    }
+
+    /// Perform boundary traversal to set the order of a 'binary tree traverser'.
+    fn boundary_order(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let curr: &Node<K, V> = tree.get_node(&node).unwrap();
+
+        // Add root node to order, then traverse left boundary, leaves, and the right boundary.
+        order.push(curr.pair.value.clone());
 
-        // If it's a leaf node, add current node to order.
-        if curr.links[1].is_none() && curr.links[2].is_none() {
-            order.append(curr.pair.value.clone());
+        if let Some(left) = &curr.links[1] {
+            Self::boundary_left(tree, order, left.clone());
+            Self::boundary_leaves(tree, order, left.clone());
         }
 
-        // Recursively traverse right child
-        if curr.links[2].is_some() {
-            self.boundary_leaves(order, curr.links[2].clone().unwrap().clone());
+        if let Some(right) = &curr.links[2] {
+            Self::boundary_leaves(tree, order, right.clone());
+            Self::boundary_right(tree, order, right.clone());
         }
     }
 
-    /// Perform left boundary traversal to set the order of this 'binary tree traverser'.
-    fn boundary_left(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Perform iterative boundary traversal of the leaf nodes to set the order of a 'binary tree
+    /// traverser'.
+    fn boundary_leaves(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let mut stack: Stack<K> = Stack::with_capacity(tree.len());
+        stack.push(node);
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+        while let Some(key) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        // If current node is not a leaf node, add it to order.
-        if curr.links[1].is_some() || curr.links[2].is_some() {
-            order.append(curr.pair.value.clone());
+            // If it's a leaf node, add current node to order.
+            if curr.links[1].is_none() && curr.links[2].is_none() {
+                order.push(curr.pair.value.clone());
+            }
 
-            // If current node has a left child, recursively traverse it as a left boundary.
-            if curr.links[1].is_some() {
-                self.boundary_left(order, curr.links[1].clone().unwrap().clone());
+            // Push the right child node and then the left child node, so the leaf nodes are
+            // popped from left to right.
+            if let Some(right) = &curr.links[2] {
+                stack.push(right.clone());
             }
-            // If current node has a right child, recursively traverse it as a left boundary.
-            else {
-                self.boundary_left(order, curr.links[2].clone().unwrap().clone());
+
+            if let Some(left) = &curr.links[1] {
+                stack.push(left.clone());
             }
         }
     }
 
-    /// Perform right boundary traversal to set the order of this 'binary tree traverser'.
-    fn boundary_right(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    /// Perform iterative left boundary traversal to set the order of a 'binary tree traverser'.
+    fn boundary_left(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let mut curr: &Node<K, V> = tree.get_node(&node).unwrap();
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
+        // While the current node is not a leaf node, add it to order and move down the left
+        // boundary, preferring the left child.
+        while curr.links[1].is_some() || curr.links[2].is_some() {
+            order.push(curr.pair.value.clone());
+
+            let next: &K = curr.links[1].as_ref().or(curr.links[2].as_ref()).unwrap();
+            curr = tree.get_node(next).unwrap();
         }
+    }
+
+    /// Perform right boundary traversal to set the order of a 'binary tree traverser'.
+    fn boundary_right(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let curr: &Node<K, V> = tree.get_node(&node).unwrap();
 
-        // If current node is not a leaf node, add it to order after traversing child node.
+        // If current node is not a leaf node, add it to order after traversing its right child
+        // node, or its left child node if it has no right child, as a left boundary.
         if curr.links[1].is_some() || curr.links[2].is_some() {
-            // If current node has a right child, recursively traverse it as a right boundary.
-            if curr.links[2].is_some() {
-                self.boundary_left(order, curr.links[2].clone().unwrap().clone());
-            }
-            // If current node has a left child, recursively traverse it as a right boundary.
-            else {
-                self.boundary_left(order, curr.links[1].clone().unwrap().clone());
-            }
+            let next: &K = curr.links[2].as_ref().or(curr.links[1].as_ref()).unwrap();
 
-            order.append(curr.pair.value.clone());
+            Self::boundary_left(tree, order, next.clone());
+            order.push(curr.pair.value.clone());
         }
     }
 
-    /// Perform iterative diagonal tree traversal to set the order of this 'binary tree
+    /// Perform iterative diagonal tree traversal to set the order of a 'binary tree
     /// traverser'.
-    fn diagonal_iter(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let mut curr: Node<K, V>;
-
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
-
-        // Using a queue, iteratively store nodes into a map whose key values are the diagonal
-        // level of the tree and whose values are a vector of nodes on that diagonal level.
-        let mut map: Map<isize, Vec<V>> = Map::new();
-        let mut queue: Queue<(K, isize)> = Queue::new();
-
-        queue.enqueue((curr.pair.key.clone(), self.tree.level_of(&curr.pair.key.clone())));
-
-        while !queue.is_empty() {
-            let qcurr = queue.dequeue();
-
-            if qcurr.is_some() {
-                if qcurr.clone().unwrap().0 == self.tree.root.clone().unwrap().pair.key {
-                    curr = self.tree.root.clone().unwrap().clone();
-                }
-                else {
-                    curr = self.tree.nodes[qcurr.clone().unwrap().0.clone()].clone();
-                }
+    fn diagonal_iter(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        // Using a queue of node keys, diagonal levels, and depths, store the nodes into groups by
+        // diagonal level. A node's diagonal level is its depth, plus one if it is a left child.
+        // The groups are kept in the order their diagonal levels are first reached.
+        let mut groups: Vec<Vec<V>> = Vec::new();
+        let mut indices: HashMap<isize, usize> = HashMap::new();
+        let mut queue: Queue<(K, isize, isize)> = Queue::with_capacity(tree.len());
+
+        queue.enqueue((node, 0, 0));
+
+        while let Some((key, level, depth)) = queue.dequeue() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
+
+            if !indices.exists(level) {
+                indices.insert(KeyValue { key: level, value: groups.len() });
+                groups.push(Vec::new());
+            }
 
-                map.insert(KeyValue { key: qcurr.clone().unwrap().1.clone(), value: Vec::new() } );
-                map[qcurr.unwrap().1.clone()].push(curr.pair.value.clone());
+            groups[indices[level]].push(curr.pair.value.clone());
 
-                if curr.links[1].is_some() {
-                    queue.enqueue((curr.links[1].clone().unwrap().clone(),
-                                   self.tree.level_of(&curr.links[1].clone().unwrap().clone()) + 1));
-                }
+            if let Some(left) = &curr.links[1] {
+                queue.enqueue((left.clone(), depth + 2, depth + 1));
+            }
 
-                if curr.links[2].is_some() {
-                    queue.enqueue((curr.links[2].clone().unwrap().clone(),
-                                   self.tree.level_of(&curr.links[2].clone().unwrap().clone())));
-                }
+            if let Some(right) = &curr.links[2] {
+                queue.enqueue((right.clone(), depth + 1, depth + 1));
             }
         }
 
         // Add nodes in diagonal level order into order.
-        for i in map.into_iter() {
-            for j in 0..i.value.len() {
-                order.append(i.value[j].clone());
-            }
+        for mut group in groups {
+            order.append(&mut group);
         }
     }
 
-    /// Perform recursive inorder tree traversal to set the order of this 'binary tree
+    /// Perform iterative inorder tree traversal to set the order of a 'binary tree
     /// traverser'.
-    fn inorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
-
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+    fn inorder_iter(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(tree.len());
+        stack.push((node, false));
+
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
+
+            // If the node's children have already been pushed, append the node's data to order.
+            if expanded {
+                order.push(curr.pair.value.clone());
+                continue;
+            }
 
-        // Perform recursive inorder traversal of the left child node.
-        if curr.links[1].is_some() {
-            self.inorder_rec(order, curr.links[1].clone().unwrap().clone());
-        }
+            // Push the right child node, the current node, and then the left child node, so they
+            // are popped in inorder.
+            if let Some(right) = &curr.links[2] {
+                stack.push((right.clone(), false));
+            }
 
-        // Append the current node's data to order.
-        order.append(curr.pair.value.clone());
+            stack.push((key.clone(), true));
 
-        // Perform recursive inorder traversal of the right child node.
-        if curr.links[2].is_some() {
-            self.inorder_rec(order, curr.links[2].clone().unwrap().clone());
+            if let Some(left) = &curr.links[1] {
+                stack.push((left.clone(), false));
+            }
         }
     }
 
-    /// Perform recursive level order tree traversal to set the order of this 'binary tree
+    /// Perform iterative level order tree traversal to set the order of a 'binary tree
     /// traverser'.
-    fn level_order_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Retrieve the height of the tree.
-        let height: isize = self.tree.height() + 1;
-
-        // For each level, perform recursive level traversal to populate order.
-        for i in 0..height {
-            self.level_order_trav(order, node.clone(), i);
-        }
-    }
+    fn level_order_iter(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let mut queue: Queue<K> = Queue::with_capacity(tree.len());
+        queue.enqueue(node);
 
-    /// Helper function for recursively performing level order traversal.
-    fn level_order_trav(&mut self, order: &mut DoublyLinkedList<V>, node: K, level: isize) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+        while let Some(key) = queue.dequeue() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
-
-        // If level is 0, append the current node's data to order.
-        if level == 0 {
-            order.append(curr.pair.value.clone());
-        }
-        // If level is not 0.
-        else {
-            // For all child nodes, perform recursive level order traversal with decrement level value.
+            // Append current node's data to order.
+            order.push(curr.pair.value.clone());
+
+            // Enqueue the child nodes, so they are dequeued after the rest of the current level.
             for i in 1..curr.links.len() {
-                if curr.links[i].is_some() {
-                    self.level_order_trav(order, curr.links[i].clone().unwrap().clone(), level - 1);
+                if let Some(child) = &curr.links[i] {
+                    queue.enqueue(child.clone());
                 }
             }
         }
     }
 
-    /// Perform recursive postorder tree traversal to set the order of this 'binary tree
+    /// Perform iterative postorder tree traversal to set the order of a 'binary tree
     /// traverser'.
-    fn postorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    fn postorder_iter(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(tree.len());
+        stack.push((node, false));
+
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
+
+            // If the node's children have already been pushed, append the node's data to order.
+            if expanded {
+                order.push(curr.pair.value.clone());
+                continue;
+            }
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+            // Push the current node and then its child nodes in reverse, so the child nodes are
+            // popped first.
+            stack.push((key.clone(), true));
 
-        // For all child nodes, perform recursive postorder traversal to populate order.
-        for i in 1..curr.links.len() {
-            if curr.links[i].is_some() {
-                self.postorder_rec(order, curr.links[i].clone().unwrap().clone());
+            for i in (1..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push((child.clone(), false));
+                }
             }
         }
-
-        // Append current node's data to order.
-        order.append(curr.pair.value.clone());
     }
 
-    /// Recursively traverses this 'tree' via preorder traversal to create the 'binary tree
+    /// Perform iterative preorder tree traversal to set the order of a 'binary tree
     /// traverser'.
-    fn preorder_rec(&mut self, order: &mut DoublyLinkedList<V>, node: K) {
-        // Set the current node based on the specified node key value.
-        let curr: Node<K, V>;
+    fn preorder_iter(tree: &BinaryTree<K, V, BALANCED>, order: &mut Vec<V>, node: K) {
+        let mut stack: Stack<K> = Stack::with_capacity(tree.len());
+        stack.push(node);
 
-        if node == self.tree.root.clone().unwrap().pair.key {
-            curr = self.tree.root.clone().unwrap().clone();
-        }
-        else {
-            curr = self.tree.nodes[node.clone()].clone();
-        }
+        while let Some(key) = stack.pop() {
+            let curr: &Node<K, V> = tree.get_node(&key).unwrap();
 
-        // Append current node's data to order.
-        order.append(curr.pair.value.clone());
+            // Append current node's data to order.
+            order.push(curr.pair.value.clone());
 
-        // For all child nodes, perform recursive preorder traversal to populate order.
-        for i in 1..curr.links.len() {
-            if curr.links[i].is_some() {
-                self.preorder_rec(order, curr.links[i].clone().unwrap().clone());
+            // Push the child nodes in reverse, so they are popped in order.
+            for i in (1..curr.links.len()).rev() {
+                if let Some(child) = &curr.links[i] {
+                    stack.push(child.clone());
+                }
             }
         }
     }
@@ -2286,6 +2904,9 @@ pub struct BinaryTree<K, V, const BALANCED: bool>
         K: PartialEq + PartialOrd + Clone + Debug + Eq + Hash,
         V: PartialEq + PartialOrd + Clone + Debug,
 {
+    /// Cached key of the node with the largest key in an unbalanced tree, set when it is first
+    /// needed and cleared when nodes are removed.
+    max: Option<K>,
     /// Hash map of nodes.
     nodes: HashMap<K, Node<K, V>>,
     /// Root node.
@@ -2300,6 +2921,7 @@ impl<K, V, const BALANCED: bool> Clear for BinaryTree<K, V, BALANCED>
 {
     /// Clears all the 'nodes' from this 'binary tree'.
     fn clear(&mut self) {
+        self.max = None;
         self.root = None;
         self.nodes.clear();
     }
@@ -2314,6 +2936,7 @@ impl<K, V, const BALANCED: bool> Clone for BinaryTree<K, V, BALANCED>
     /// Returns a clone of this 'binary tree'.
     fn clone(&self) -> Self {
         BinaryTree {
+            max: self.max.clone(),
             nodes: self.nodes.clone(),
             root: self.root.clone(),
         }
@@ -2423,7 +3046,7 @@ impl<K, V, const BALANCED: bool> IntoIterator for BinaryTree<K, V, BALANCED>
             return vec.into_iter();
         }
 
-        let mut trav = self.clone().into_trav();
+        let mut trav = self.trav();
 
         // Traverse the tree inorder.
         while trav.has_next() {
@@ -2460,23 +3083,7 @@ impl<K, V, const BALANCED: bool> IntoTraverser<K> for BinaryTree<K, V, BALANCED>
 
     /// Converts this 'tree' into a 'traverser'.
     fn into_trav(self) -> Self::IntoTrav {
-        let mut t: BinaryTreeTraverser<K, V, BALANCED> = BinaryTreeTraverser {
-            mode: BinaryTreeTraversalMode::Inorder,
-            trav: DoublyLinkedListTraverser::new(),
-            tree: self.clone(),
-        };
-
-        // Traverse the tree inorder and store the order of the nodes.
-        let mut order: DoublyLinkedList<V> = DoublyLinkedList::new();
-
-        if self.root.is_some() {
-            t.inorder_rec(&mut order, self.root.unwrap().pair.key.clone());
-        }
-
-        // Set trav to the order converted into a traverser.
-        t.trav = order.clone().into_trav();
-
-        t
+        BinaryTreeTraverser::from_tree(&self)
     }
 }
 
@@ -2501,8 +3108,8 @@ impl<K, V, const BALANCED: bool> PartialEq for BinaryTree<K, V, BALANCED>
     /// contain the same 'nodes' in the same order with the same values.
     fn eq(&self, other: &Self) -> bool {
         // Convert both trees into traversers.
-        let mut trav1 = self.clone().into_trav();
-        let mut trav2 = other.clone().into_trav();
+        let mut trav1 = self.trav();
+        let mut trav2 = other.trav();
 
         // If lengths do not match, return false.
         if self.len() != other.len() {
@@ -2548,22 +3155,12 @@ impl<K, V, const BALANCED: bool> Collection for BinaryTree<K, V, BALANCED>
             return false;
         }
 
-        // If item matches the root node, return true.
-        if self.root.clone().unwrap().pair == *item {
-            return true;
-        }
-
-        // If the item matches any node in the tree, return true.
-        let vec = self.nodes.clone().to_vec();
-
-        for i in 0..vec.len() {
-            if vec[i].value.pair == *item {
-                return true;
-            }
+        // Look up the node by the item's key rather than cloning and scanning every node, since
+        // keys are unique. Return true if the node's pair matches item.
+        match self.get_node(&item.key) {
+            Some(node) => node.pair == *item,
+            None => false,
         }
-
-        // If item does not match a node in the tree, return false.
-        false
     }
 
     /// Returns true if this 'binary tree' contains the specified vector.
@@ -2587,22 +3184,9 @@ impl<K, V, const BALANCED: bool> Collection for BinaryTree<K, V, BALANCED>
             return vec;
         }
 
-        let mut trav = self.clone().into_trav();
-
-        // Traverse the tree and add all nodes to the vector following inorder traversal.
-        while trav.has_next() {
-            let data: V = trav.next().unwrap().clone();
-
-            if data == self.root.clone().unwrap().pair.value {
-                vec.push(self.root.clone().unwrap().pair.clone());
-            }
-
-            for i in self.nodes.clone().into_iter() {
-                if i.value.pair.value == data {
-                    vec.push(i.value.pair.clone());
-                }
-            }
-        }
+        // Walk the borrowed nodes following inorder traversal instead of cloning this tree into a
+        // traverser and scanning every node for each traversed value.
+        self.inorder_pairs(&mut vec, &self.root.as_ref().unwrap().pair.key);
 
         vec
     }
@@ -2655,6 +3239,19 @@ impl<K, V, const BALANCED: bool> MapCollection<K, V> for BinaryTree<K, V, BALANC
             return false;
         }
 
+        // If this is an unbalanced tree and the new key is larger than every other key, insert the
+        // new node as the right child of the largest node. This keeps inserting keys in ascending
+        // order from walking down the whole tree each time.
+        if !BALANCED {
+            if let Some(max) = self.max_node() {
+                if pair.key > max {
+                    self.insert_right_of(max, &pair);
+                    self.max = Some(pair.key);
+                    return true;
+                }
+            }
+        }
+
         // Insert the new node starting from the root node, if there is one.
         match &self.root {
             Some(r) => self.insert_rec(Some(r.pair.key.clone()), &pair),
@@ -2672,6 +3269,8 @@ impl<K, V, const BALANCED: bool> MapCollection<K, V> for BinaryTree<K, V, BALANC
             return false;
         }
 
+        self.max = None;
+
         // Remove the node with the specified key
         self.remove_rec(Some(self.root.clone().unwrap().pair.key.clone()), key.clone());
 
@@ -2865,7 +3464,7 @@ impl<K, V, const BALANCED: bool> TraversableCollection<K, V> for BinaryTree<K, V
     /// pairs of each 'node' in the path and is stored in order from key_a at the start to
     /// key_b at the end. For a 'binary tree', this retrieves key_a's subtree and uses binary
     /// search to find the path to key_b, if it exists.
-    fn path_of(&mut self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
+    fn path_of(&self, key_a: K, key_b: K) -> Option<DoublyLinkedList<KeyValue<usize, V>>> {
         // If key_a and key_b are valid.
         if self.exists(key_a.clone()) && self.exists(key_b.clone()) {
             let mut path: DoublyLinkedList<KeyValue<usize, V>> = DoublyLinkedList::new();
@@ -3169,6 +3768,33 @@ impl<K, V, const BALANCED: bool> TreeCollection<K, V> for BinaryTree<K, V, BALAN
         height
     }
 
+    /// Returns the number of internal 'nodes' in this 'binary tree'. An internal 'node' is a 'node' that
+    /// has at least one child 'node'.
+    fn internal_count(&self) -> usize {
+        // If there is no root node (aka no tree), return 0.
+        if self.root.is_none() {
+            return 0;
+        }
+
+        let mut count: usize = 0;
+
+        // Count the root node if it has children.
+        if Self::child_count(self.root.as_ref().unwrap()) > 0 {
+            count += 1;
+        }
+
+        // Count every other node that has children.
+        let nodes: Vec<KeyValue<K, Node<K, V>>> = self.nodes.to_vec();
+
+        for node in nodes.iter() {
+            if Self::child_count(&node.value) > 0 {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
     /// Returns true if the 'node' with the second specified key is an ancestor of the 'node' with
     /// the first specified key. If either key does not belong to an existing 'node', or the two
     /// 'nodes' are not ancestors, this returns false. An ancestor of a 'node' is a 'node' that
@@ -3320,6 +3946,48 @@ impl<K, V, const BALANCED: bool> TreeCollection<K, V> for BinaryTree<K, V, BALAN
     /// 'node'. This is the same as the depth of a 'node'.
     fn level_of(&self, key: &K) -> isize { self.depth_of(key) }
 
+    /// Returns the values of the 'nodes' in this 'binary tree' grouped by level, with the root
+    /// 'node' at index 0. An empty 'binary tree' returns an empty vector.
+    fn levels(&self) -> Vec<Vec<V>> {
+        let mut levels: Vec<Vec<V>> = Vec::new();
+
+        // If there is no root node (aka no tree), return an empty vector.
+        if self.root.is_none() {
+            return levels;
+        }
+
+        let mut queue: Queue<K> = Queue::with_capacity(self.len());
+        queue.enqueue(self.root.clone().unwrap().pair.key.clone());
+
+        // Perform iterative level order traversal.
+        while !queue.is_empty() {
+            // Store the queue's current length.
+            let mut len: usize = queue.len();
+            let mut level: Vec<V> = Vec::with_capacity(len);
+
+            // Go through the current nodes in the queue.
+            while len > 0 {
+                let node: &Node<K, V> = self.get_node(&queue.dequeue().unwrap()).unwrap();
+
+                // Add the node's value to the current level and its children to the queue.
+                level.push(node.pair.value.clone());
+
+                for i in 1..node.links.len() {
+                    if let Some(child) = &node.links[i] {
+                        queue.enqueue(child.clone());
+                    }
+                }
+
+                // Decrement the stored length.
+                len -= 1;
+            }
+
+            levels.push(level);
+        }
+
+        levels
+    }
+
     /// Returns the parent 'node' of the 'node' with the specified key. If no such 'node' exists or
     /// if the 'node' has no parent, this returns None.
     fn parent_node(&self, key: &K) -> Option<&V> {
@@ -3371,6 +4039,84 @@ impl<K, V, const BALANCED: bool> TreeCollection<K, V> for BinaryTree<K, V, BALAN
         ret
     }
 
+    /// Returns an indented text diagram of this 'binary tree', with one line per 'node' showing
+    /// its key and value. Child 'nodes' are drawn below their parent using branch characters, and
+    /// are marked with (L) or (R) for the side of their parent they are on. An empty 'binary tree'
+    /// returns an empty string.
+    fn to_ascii(&self) -> String {
+        let mut ascii: String = String::new();
+
+        // If there is no root node (aka no tree), return an empty string.
+        if self.root.is_none() {
+            return ascii;
+        }
+
+        // Each entry holds a node's key, the prefix of its line, its branch characters, and its
+        // side marker.
+        let mut stack: Vec<(K, String, &str, &str)> = Vec::new();
+        stack.push((self.root.clone().unwrap().pair.key.clone(), String::new(), "", ""));
+
+        // Perform iterative preorder traversal, writing one line per node.
+        while let Some((key, prefix, branch, side)) = stack.pop() {
+            let node: &Node<K, V> = self.get_node(&key).unwrap();
+
+            ascii.push_str(&format!("{}{}{}{:?}: {:?}\n", prefix, branch, side, node.pair.key,
+                node.pair.value));
+
+            // Get the child prefix, continuing the parent's branch line if it has more siblings.
+            let child_prefix: String = match branch {
+                "├── " => format!("{}│   ", prefix),
+                "└── " => format!("{}    ", prefix),
+                _ => prefix,
+            };
+
+            let mut children: Vec<(K, &str)> = Vec::new();
+
+            if let Some(left) = &node.links[1] {
+                children.push((left.clone(), "(L) "));
+            }
+
+            if let Some(right) = &node.links[2] {
+                children.push((right.clone(), "(R) "));
+            }
+
+            // Push the children in reverse so the left child is written first.
+            for i in (0..children.len()).rev() {
+                let child_branch: &str = if i == children.len() - 1 { "└── " } else { "├── " };
+                stack.push((children[i].0.clone(), child_prefix.clone(), child_branch,
+                    children[i].1));
+            }
+        }
+
+        ascii
+    }
+
+    /// Returns a 'doubly linked list' containing the key value pairs of this 'binary tree' in the
+    /// order of the specified 'traversal mode'.
+    fn to_list(&self, mode: TraversalMode) -> DoublyLinkedList<KeyValue<K, V>> {
+        // Traverse a copy of this binary tree with each value replaced by its key to get the key
+        // order.
+        let mut trav: BinaryTreeTraverser<K, K, BALANCED> =
+            self.map_pairs(|pair| pair.key.clone()).into_trav();
+
+        match mode {
+            TraversalMode::Inorder => trav.inorder(),
+            TraversalMode::LevelOrder => trav.level_order(),
+            TraversalMode::Postorder => trav.postorder(),
+            TraversalMode::Preorder => trav.preorder(),
+        }
+
+        let mut list: DoublyLinkedList<KeyValue<K, V>> = DoublyLinkedList::new();
+
+        // Append each key value pair in traversal order.
+        while trav.has_next() {
+            let key: K = trav.next().unwrap();
+            list.append(KeyValue { key: key.clone(), value: self.get(key).unwrap().clone() });
+        }
+
+        list
+    }
+
     /// Returns the width of the specified level of this 'tree'. This returns 0 if the specified
     /// level does not exist in this 'tree'. The width of a level is the number of 'nodes' in that
     /// level.
@@ -3397,6 +4143,7 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
     #[allow(dead_code)]
     pub fn new() -> Self {
         let new: BinaryTree<K, V, BALANCED> = BinaryTree {
+            max: None,
             nodes: HashMap::new(),
             root: None,
         };
@@ -3407,6 +4154,7 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
     /// Creates a new 'binary tree' with the specified root 'node'.
     pub fn new_root(pair: KeyValue<K, V>) -> Self {
         let mut new: BinaryTree<K, V, BALANCED> = BinaryTree {
+            max: None,
             nodes: HashMap::new(),
             root: Some(Node {
                 pair: pair.clone(),
@@ -3538,6 +4286,85 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
         lheight - rheight
     }
 
+    /// Returns the number of child 'nodes' the specified 'node' has.
+    fn child_count(node: &Node<K, V>) -> usize {
+        let mut count: usize = 0;
+
+        for i in 1..node.links.len() {
+            if node.links[i].is_some() {
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Returns the smallest key in this 'binary tree' that is greater than or equal to the
+    /// specified key, or None if there is no such key. This follows a single path down from the
+    /// root 'node', so it takes time proportional to the height of this 'binary tree'.
+    #[allow(dead_code)]
+    pub fn ceiling(&self, key: &K) -> Option<&K> {
+        let mut next: Option<&Node<K, V>> = self.root.as_ref();
+        let mut best: Option<&K> = None;
+
+        while let Some(node) = next {
+            // If the key matches, return it.
+            if node.pair.key == *key {
+                return Some(&node.pair.key);
+            }
+
+            // If the node's key is greater than the key, it is the best so far, so look for a
+            // closer key in its left subtree. Otherwise, look in its right subtree.
+            let child: &Option<K> = if node.pair.key > *key {
+                best = Some(&node.pair.key);
+                &node.links[1]
+            }
+            else {
+                &node.links[2]
+            };
+
+            next = match child {
+                Some(k) => self.get_node(k),
+                None => None,
+            };
+        }
+
+        best
+    }
+
+    /// Returns the largest key in this 'binary tree' that is less than or equal to the specified
+    /// key, or None if there is no such key. This follows a single path down from the root
+    /// 'node', so it takes time proportional to the height of this 'binary tree'.
+    #[allow(dead_code)]
+    pub fn floor(&self, key: &K) -> Option<&K> {
+        let mut next: Option<&Node<K, V>> = self.root.as_ref();
+        let mut best: Option<&K> = None;
+
+        while let Some(node) = next {
+            // If the key matches, return it.
+            if node.pair.key == *key {
+                return Some(&node.pair.key);
+            }
+
+            // If the node's key is less than the key, it is the best so far, so look for a closer
+            // key in its right subtree. Otherwise, look in its left subtree.
+            let child: &Option<K> = if node.pair.key < *key {
+                best = Some(&node.pair.key);
+                &node.links[2]
+            }
+            else {
+                &node.links[1]
+            };
+
+            next = match child {
+                Some(k) => self.get_node(k),
+                None => None,
+            };
+        }
+
+        best
+    }
+
     /// Returns the maximum depth of this 'binary tree'. This is used to calculate this 'tree's'
     /// diameter.
     fn get_max_depth(&self, node: K, diameter: &mut usize) -> usize {
@@ -3624,6 +4451,47 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
         }
     }
 
+    /// Returns the 'node' with the specified key, or None if no such 'node' exists in this
+    /// 'binary tree'.
+    fn get_node(&self, key: &K) -> Option<&Node<K, V>> {
+        match &self.root {
+            // If key matches the root node, return the root node.
+            Some(r) if r.pair.key == *key => Some(r),
+            Some(_) => self.nodes.get(key.clone()),
+            None => None,
+        }
+    }
+
+    /// Adds the key value pairs of the specified 'node' and its descendants to the specified
+    /// vector following inorder traversal.
+    fn inorder_pairs(&self, vec: &mut Vec<KeyValue<K, V>>, node: &K) {
+        // Stack of node keys paired with whether the node's children have already been pushed.
+        let mut stack: Stack<(K, bool)> = Stack::with_capacity(self.len());
+        stack.push((node.clone(), false));
+
+        while let Some((key, expanded)) = stack.pop() {
+            let curr: &Node<K, V> = self.get_node(&key).unwrap();
+
+            // If the node's children have already been pushed, add the node's pair to the vector.
+            if expanded {
+                vec.push(curr.pair.clone());
+                continue;
+            }
+
+            // Push the right child node, the current node, and then the left child node, so they
+            // are popped in inorder.
+            if let Some(right) = &curr.links[2] {
+                stack.push((right.clone(), false));
+            }
+
+            stack.push((key.clone(), true));
+
+            if let Some(left) = &curr.links[1] {
+                stack.push((left.clone(), false));
+            }
+        }
+    }
+
     /// Recursively inserts a new 'node' based on its key value.
     fn insert_rec(&mut self, node: Option<K>, pair: &KeyValue<K, V>) {
         // If there is no root node, insert the new node as the root node.
@@ -3917,6 +4785,205 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
         }
     }
 
+    /// Inserts a new 'node' with the specified key and value as the right child of the 'node' with
+    /// the specified key, which must not have a right child.
+    fn insert_right_of(&mut self, parent: K, pair: &KeyValue<K, V>) {
+        self.nodes.insert(
+            KeyValue {
+                key: pair.key.clone(),
+                value: Node {
+                    pair: pair.clone(),
+                    links: vec![Some(parent.clone()), None, None],
+                }});
+
+        match &mut self.root {
+            Some(ref mut r) if r.pair.key == parent => r.links[2] = Some(pair.key.clone()),
+            _ => self.nodes[parent].links[2] = Some(pair.key.clone()),
+        }
+    }
+
+    /// Returns true if this 'binary tree' is complete, meaning every level is filled except
+    /// possibly the last level, which is filled from left to right. An empty 'binary tree' is
+    /// complete.
+    #[allow(dead_code)]
+    pub fn is_complete(&self) -> bool {
+        // If there is no root node (aka no tree), return true.
+        if self.root.is_none() {
+            return true;
+        }
+
+        let mut queue: Queue<K> = Queue::with_capacity(self.len());
+        let mut gap: bool = false;
+        queue.enqueue(self.root.clone().unwrap().pair.key.clone());
+
+        // Perform a level order traversal, checking for a child after a missing child.
+        while !queue.is_empty() {
+            let key: K = queue.dequeue().unwrap();
+            let node: &Node<K, V> = self.get_node(&key).unwrap();
+
+            for i in 1..3 {
+                match &node.links[i] {
+                    Some(child) => {
+                        // If a child follows a missing child, return false.
+                        if gap {
+                            return false;
+                        }
+
+                        queue.enqueue(child.clone());
+                    },
+                    None => gap = true,
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Returns true if this 'binary tree' is full, meaning every 'node' has either zero or two
+    /// child 'nodes'. An empty 'binary tree' is full.
+    #[allow(dead_code)]
+    pub fn is_full(&self) -> bool {
+        // If there is no root node (aka no tree), return true.
+        if self.root.is_none() {
+            return true;
+        }
+
+        // If the root node has only one child, return false.
+        if Self::child_count(self.root.as_ref().unwrap()) == 1 {
+            return false;
+        }
+
+        // If any other node has only one child, return false.
+        let nodes: Vec<KeyValue<K, Node<K, V>>> = self.nodes.to_vec();
+
+        for node in nodes.iter() {
+            if Self::child_count(&node.value) == 1 {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns a new 'binary tree' with the same structure and keys as this 'binary tree', but
+    /// with each value set to the result of calling the specified function on the original key
+    /// value pair.
+    fn map_pairs<U, F: Fn(&KeyValue<K, V>) -> U>(&self, f: F) -> BinaryTree<K, U, BALANCED>
+        where
+            U: PartialEq + PartialOrd + Clone + Debug,
+    {
+        let mut tree: BinaryTree<K, U, BALANCED> = BinaryTree::new();
+
+        // Map the root node's pair, keeping its links.
+        if let Some(r) = &self.root {
+            tree.root = Some(Node {
+                pair: KeyValue { key: r.pair.key.clone(), value: f(&r.pair) },
+                links: r.links.clone(),
+            });
+        }
+
+        // Map the pair of every other node, keeping its links.
+        let nodes: Vec<KeyValue<K, Node<K, V>>> = self.nodes.to_vec();
+
+        for node in nodes.iter() {
+            tree.nodes.insert(
+                KeyValue {
+                    key: node.key.clone(),
+                    value: Node {
+                        pair: KeyValue {
+                            key: node.value.pair.key.clone(),
+                            value: f(&node.value.pair),
+                        },
+                        links: node.value.links.clone(),
+                    }});
+        }
+
+        tree
+    }
+
+    /// Returns a new 'binary tree' with the same structure and keys as this 'binary tree', but
+    /// with each value set to the result of calling the specified function on the original value.
+    #[allow(dead_code)]
+    pub fn map_values<U, F: Fn(&V) -> U>(&self, f: F) -> BinaryTree<K, U, BALANCED>
+        where
+            U: PartialEq + PartialOrd + Clone + Debug,
+    {
+        self.map_pairs(|pair| f(&pair.value))
+    }
+
+    /// Returns the largest key in this 'binary tree' by following the right child 'nodes' from
+    /// the root 'node', or None if this 'binary tree' is empty.
+    #[allow(dead_code)]
+    pub fn max_key(&self) -> Option<&K> {
+        let mut node: &Node<K, V> = self.root.as_ref()?;
+
+        // Follow the right child nodes until a node with no right child is reached.
+        while let Some(child) = &node.links[2] {
+            node = self.get_node(child).unwrap();
+        }
+
+        Some(&node.pair.key)
+    }
+
+    /// Returns the key of the 'node' with the largest key in this 'binary tree', or None if this
+    /// 'binary tree' is empty. The key is cached so later calls do not follow the right child
+    /// 'nodes' again until a 'node' is removed.
+    fn max_node(&mut self) -> Option<K> {
+        if self.max.is_none() {
+            self.max = self.max_key().cloned();
+        }
+
+        self.max.clone()
+    }
+
+    /// Returns the smallest key in this 'binary tree' by following the left child 'nodes' from
+    /// the root 'node', or None if this 'binary tree' is empty.
+    #[allow(dead_code)]
+    pub fn min_key(&self) -> Option<&K> {
+        let mut node: &Node<K, V> = self.root.as_ref()?;
+
+        // Follow the left child nodes until a node with no left child is reached.
+        while let Some(child) = &node.links[1] {
+            node = self.get_node(child).unwrap();
+        }
+
+        Some(&node.pair.key)
+    }
+
+    /// Returns the number of keys in this 'binary tree' that are strictly less than the specified
+    /// key. If the key is in this 'binary tree', this is its 0-based position in inorder
+    /// traversal. The specified key does not need to be in this 'binary tree'.
+    #[allow(dead_code)]
+    pub fn rank(&self, key: &K) -> Option<usize> {
+        let mut next: Option<&K> = self.root.as_ref().map(|r| &r.pair.key);
+        let mut stack: Vec<&Node<K, V>> = Vec::new();
+        let mut count: usize = 0;
+
+        loop {
+            // Push the left child nodes until a node with no left child is reached.
+            while let Some(k) = next {
+                let node: &Node<K, V> = self.get_node(k).unwrap();
+                stack.push(node);
+                next = node.links[1].as_ref();
+            }
+
+            // If every node has been visited, every key is less than the specified key.
+            let curr: &Node<K, V> = match stack.pop() {
+                Some(n) => n,
+                None => return Some(count),
+            };
+
+            // If a key that is not less than the specified key has been reached, return the
+            // number of nodes visited before it.
+            if curr.pair.key >= *key {
+                return Some(count);
+            }
+
+            count += 1;
+            next = curr.links[2].as_ref();
+        }
+    }
+
     /// Recursively removes the 'node' with the specified key.
     fn remove_rec(&mut self, node: Option<K>, key: K) -> Option<K> {
         // If node is None, return it.
@@ -4190,13 +5257,42 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
         }
     }
 
+    /// Returns the k-th smallest key in this 'binary tree', counting from 0, or None if this
+    /// 'binary tree' has k or fewer keys.
+    #[allow(dead_code)]
+    pub fn select(&self, k: usize) -> Option<&K> {
+        let mut next: Option<&K> = Some(&self.root.as_ref()?.pair.key);
+        let mut stack: Vec<&Node<K, V>> = Vec::new();
+        let mut count: usize = 0;
+
+        loop {
+            // Push the left child nodes until a node with no left child is reached.
+            while let Some(key) = next {
+                let node: &Node<K, V> = self.get_node(key).unwrap();
+                stack.push(node);
+                next = node.links[1].as_ref();
+            }
+
+            // If every node has been visited, return None.
+            let curr: &Node<K, V> = stack.pop()?;
+
+            // If k nodes have been visited before this one, return its key.
+            if count == k {
+                return Some(&curr.pair.key);
+            }
+
+            count += 1;
+            next = curr.links[2].as_ref();
+        }
+    }
+
     /// Returns a subtree with the specified 'node' in this 'binary tree' set as the root 'node'
     /// in the returned subtree.
     ///
     /// # Panics
     ///
     /// This function panics if the specified 'node' does not exist in this 'binary tree'.
-    pub fn subtree(&mut self, node: K) -> BinaryTree<K, V, BALANCED> {
+    pub fn subtree(&self, node: K) -> BinaryTree<K, V, BALANCED> {
         // Panic the the specified node is not in the tree.
         if !self.exists(node.clone()) {
             panic!("Cannot create subtree due to non-existent node specified.");
@@ -4290,4 +5386,44 @@ impl<K, V, const BALANCED: bool> BinaryTree<K, V, BALANCED>
 
         sub
     }
+
+    /// Returns an undirected unweighted 'graph' containing a 'node' for each 'node' in this
+    /// 'binary tree' and an 'edge' between each parent 'node' and each of its child 'nodes'. The
+    /// 'graph' keys are assigned in level order, so the root 'node' has key 0.
+    #[allow(dead_code)]
+    pub fn to_graph(&self) -> UUGraph<V> {
+        let mut graph: UUGraph<V> = Graph::new();
+        let list: Vec<KeyValue<usize, KeyValue<K, V>>> =
+            self.to_list(TraversalMode::LevelOrder).to_vec();
+        let mut indices: HashMap<K, usize> = HashMap::new();
+
+        // Add a graph node for each tree node in level order, remembering its graph key.
+        for (i, item) in list.iter().enumerate() {
+            indices.insert(KeyValue { key: item.value.key.clone(), value: i });
+            graph.insert(KeyValue { key: i, value: item.value.value.clone() });
+        }
+
+        // Connect each node to its child nodes, skipping empty child links.
+        for item in list.iter() {
+            let node: &Node<K, V> = self.get_node(&item.value.key).unwrap();
+
+            for child in node.links.iter().skip(1).flatten() {
+                graph.connect(Edge {
+                    node_a: indices[item.value.key.clone()],
+                    node_b: indices[child.clone()],
+                    weight: 1.0,
+                });
+            }
+        }
+
+        graph
+    }
+
+    /// Returns a 'traverser' for this 'binary tree' without consuming or copying it. The
+    /// 'traverser' starts in inorder traversal mode and holds only the order of this 'binary
+    /// tree's' values in each traversal mode.
+    #[allow(dead_code)]
+    pub fn trav(&self) -> BinaryTreeTraverser<K, V, BALANCED> {
+        BinaryTreeTraverser::from_tree(self)
+    }
 }
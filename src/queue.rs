@@ -7,9 +7,10 @@
 pub mod deque;
 
 use core::fmt::{Debug, Formatter};
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a 'queue'.
 pub trait QueueCollection<T>: Collection + Full
@@ -25,6 +26,10 @@ pub trait QueueCollection<T>: Collection + Full
 
     /// Returns the first element in the 'queue' or None if there isn't one.
     fn peek(&self) -> Option<&T>;
+
+    /// Returns a mutable reference to the first element in the 'queue' or None if there isn't
+    /// one.
+    fn peek_mut(&mut self) -> Option<&mut T>;
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -35,6 +40,7 @@ const DEF_QUEUE_CAPACITY: usize = 10;
 
 /// A collection that dequeues (removes) elements from the front and enqueues (adds) elements to
 /// the end.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Queue<T>
     where
         T: PartialEq + PartialOrd + Clone + Debug,
@@ -78,6 +84,17 @@ impl<T> Debug for Queue<T>
     }
 }
 
+// Default function for Queue
+impl<T> Default for Queue<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Returns a new empty 'queue' with a default capacity of 10.
+    fn default() -> Self {
+        Queue::new()
+    }
+}
+
 // Empty function for Queue
 impl<T> Empty for Queue<T>
     where
@@ -89,6 +106,60 @@ impl<T> Empty for Queue<T>
     }
 }
 
+// Extend function for Queue
+impl<T> Extend<T> for Queue<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Extends this 'queue' with the elements of the specified iterator. The elements are added
+    /// to the back of this 'queue' in order and are not limited by its capacity.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.deq.push_back(i);
+        }
+    }
+}
+
+// From function for Queue
+impl<T> From<Vec<T>> for Queue<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'queue' that contains the elements of the specified vector. The first element
+    /// of the vector becomes the front of the 'queue'. The 'queue' is given room for more
+    /// elements, so it does not start out full.
+    fn from(vec: Vec<T>) -> Self {
+        let mut deq: VecDeque<T> = VecDeque::with_capacity(vec.len() + DEF_QUEUE_CAPACITY);
+        deq.extend(vec);
+        Queue { deq }
+    }
+}
+
+// From function for Vec
+impl<T> From<Queue<T>> for Vec<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new vector that contains the elements of the specified 'queue'. The elements are
+    /// ordered from the front of the 'queue' to the back.
+    fn from(queue: Queue<T>) -> Self {
+        Vec::from(queue.deq)
+    }
+}
+
+// FromIterator function for Queue
+impl<T> FromIterator<T> for Queue<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'queue' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut queue: Queue<T> = Queue { deq: VecDeque::new() };
+        queue.extend(iter);
+        queue
+    }
+}
+
 // Full function for Queue
 impl<T> Full for Queue<T>
     where
@@ -156,12 +227,14 @@ impl<V> Reversible for Queue<V>
     where
         V: Clone + Debug + PartialEq + PartialOrd,
 {
-    /// Returns a copy of this 'queue' in reverse order.
+    /// Returns a copy of this 'queue' in reverse order, meaning the back element becomes the front
+    /// element. This 'queue' is not changed. Use 'reverse_in_place' to reverse this 'queue'
+    /// itself.
     fn reverse(&mut self) -> Self {
-        let mut rev: Queue<V> = Queue::new();
+        let mut rev: Queue<V> = Queue::with_capacity(self.capacity());
 
         for i in (0..self.len()).rev() {
-            rev.enqueue(self.deq[i].clone());
+            rev.deq.push_back(self.deq[i].clone());
         }
 
         rev
@@ -234,6 +307,12 @@ impl<T> QueueCollection<T> for Queue<T>
     fn peek(&self) -> Option<&T> {
         self.deq.front()
     }
+
+    /// Returns a mutable reference to the first element in the 'queue' or None if there isn't
+    /// one.
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.deq.front_mut()
+    }
 }
 
 // Queue functions
@@ -258,6 +337,45 @@ impl<T> Queue<T>
         queue
     }
 
+    /// Removes up to the specified number of elements from the front of this 'queue'. Returns a
+    /// vector of the removed elements in the order they were removed, which has fewer elements
+    /// than specified if this 'queue' empties first.
+    #[allow(dead_code)]
+    pub fn dequeue_n(&mut self, n: usize) -> Vec<T> {
+        let count: usize = n.min(self.deq.len());
+
+        self.deq.drain(..count).collect()
+    }
+
+    /// Removes all elements from this 'queue' and returns them in a vector from the front to the
+    /// back, meaning in the order they would be dequeued. The capacity of this 'queue' is kept.
+    #[allow(dead_code)]
+    pub fn drain(&mut self) -> Vec<T> {
+        self.deq.drain(..).collect()
+    }
+
+    /// Appends the elements of the specified vector to the back of this 'queue' in order. The
+    /// elements are not limited by the capacity of this 'queue', which grows to fit them.
+    #[allow(dead_code)]
+    pub fn enqueue_all(&mut self, items: Vec<T>) {
+        self.deq.extend(items);
+    }
+
+    /// Reverses the order of the elements in this 'queue', meaning the front element becomes the
+    /// back element.
+    #[allow(dead_code)]
+    pub fn reverse_in_place(&mut self) {
+        self.deq.make_contiguous().reverse();
+    }
+
+    /// Shrinks the capacity of this 'queue' as close to its length as possible, releasing unused
+    /// memory. Since a 'queue' is full when its length equals its capacity, it may be full
+    /// afterwards.
+    #[allow(dead_code)]
+    pub fn shrink_to_fit(&mut self) {
+        self.deq.shrink_to_fit();
+    }
+
     /// Creates a new 'queue' with the specified capacity.
     #[allow(dead_code)]
     pub fn with_capacity(capacity: usize) -> Self {
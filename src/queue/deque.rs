@@ -5,11 +5,12 @@
 //! from either end.
 
 use core::fmt::{Debug, Formatter};
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
-use crate::queue::QueueCollection;
-use crate::stack::StackCollection;
+use crate::queue::{Queue, QueueCollection};
+use crate::stack::{Stack, StackCollection};
+use crate::prelude::*;
 
 /// The default capacity for a 'deque'.
 const DEF_DEQUE_CAPACITY: usize = 10;
@@ -31,6 +32,7 @@ pub trait DequeCollection<T>: QueueCollection<T> + StackCollection<T>
 // Deque
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A collection that implements a double-ended 'queue' with 'stack' operations.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Deque<T>
     where
         T: PartialEq + PartialOrd + Clone + Debug,
@@ -74,6 +76,17 @@ impl<T> Debug for Deque<T>
     }
 }
 
+// Default function for Deque
+impl<T> Default for Deque<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Returns a new empty 'deque' with a default capacity of 10.
+    fn default() -> Self {
+        Deque::new()
+    }
+}
+
 // Empty function for Deque
 impl<T> Empty for Deque<T>
     where
@@ -85,6 +98,60 @@ impl<T> Empty for Deque<T>
     }
 }
 
+// Extend function for Deque
+impl<T> Extend<T> for Deque<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Extends this 'deque' with the elements of the specified iterator. The elements are added
+    /// to the back of this 'deque' in order and are not limited by its capacity.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.deq.push_back(i);
+        }
+    }
+}
+
+// From function for Deque
+impl<T> From<Vec<T>> for Deque<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'deque' that contains the elements of the specified vector. The first element
+    /// of the vector becomes the front of the 'deque'. The 'deque' is given room for more
+    /// elements, so it does not start out full.
+    fn from(vec: Vec<T>) -> Self {
+        let mut deq: VecDeque<T> = VecDeque::with_capacity(vec.len() + DEF_DEQUE_CAPACITY);
+        deq.extend(vec);
+        Deque { deq }
+    }
+}
+
+// From function for Vec
+impl<T> From<Deque<T>> for Vec<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new vector that contains the elements of the specified 'deque'. The elements are
+    /// ordered from the front of the 'deque' to the back.
+    fn from(deque: Deque<T>) -> Self {
+        Vec::from(deque.deq)
+    }
+}
+
+// FromIterator function for Deque
+impl<T> FromIterator<T> for Deque<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'deque' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut deque: Deque<T> = Deque { deq: VecDeque::new() };
+        deque.extend(iter);
+        deque
+    }
+}
+
 // Full function for Deque
 impl<T> Full for Deque<T>
     where
@@ -152,12 +219,14 @@ impl<T> Reversible for Deque<T>
     where
         T: PartialEq + PartialOrd + Clone + Debug,
 {
-    /// Returns a copy of this 'deque' in reverse order.
+    /// Returns a copy of this 'deque' in reverse order, meaning the back element becomes the front
+    /// element. This 'deque' is not changed. Use 'reverse_in_place' to reverse this 'deque'
+    /// itself.
     fn reverse(&mut self) -> Self {
-        let mut rev: Deque<T> = Deque::new();
+        let mut rev: Deque<T> = Deque::with_capacity(self.capacity());
 
         for i in (0..self.len()).rev() {
-            rev.push(self.deq[i].clone());
+            rev.deq.push_back(self.deq[i].clone());
         }
 
         rev
@@ -230,6 +299,12 @@ impl<T> QueueCollection<T> for Deque<T>
     fn peek(&self) -> Option<&T> {
         self.deq.front()
     }
+
+    /// Returns a mutable reference to the first element in the 'deque' or None if there isn't
+    /// one.
+    fn peek_mut(&mut self) -> Option<&mut T> {
+        self.deq.front_mut()
+    }
 }
 
 // StackCollection functions for Deque
@@ -295,6 +370,74 @@ impl<T> Deque<T>
         deque
     }
 
+    /// Returns a pair of slices that together contain the elements of this 'deque' in order, from
+    /// front to back. The elements are not copied. If this 'deque' is contiguous, the second slice
+    /// is empty.
+    #[allow(dead_code)]
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.deq.as_slices()
+    }
+
+    /// Removes up to the specified number of elements from the front of this 'deque'. Returns a
+    /// vector of the removed elements in the order they were removed, which has fewer elements
+    /// than specified if this 'deque' empties first.
+    #[allow(dead_code)]
+    pub fn dequeue_n(&mut self, n: usize) -> Vec<T> {
+        let count: usize = n.min(self.deq.len());
+
+        self.deq.drain(..count).collect()
+    }
+
+    /// Removes all elements from this 'deque' and returns them in a vector from the front to the
+    /// back, meaning in the order they would be dequeued. The capacity of this 'deque' is kept.
+    #[allow(dead_code)]
+    pub fn drain(&mut self) -> Vec<T> {
+        self.deq.drain(..).collect()
+    }
+
+    /// Appends the elements of the specified vector to the back of this 'deque' in order. The
+    /// elements are not limited by the capacity of this 'deque', which grows to fit them.
+    #[allow(dead_code)]
+    pub fn enqueue_all(&mut self, items: Vec<T>) {
+        self.deq.extend(items);
+    }
+
+    /// Converts this 'deque' into a 'queue' that contains its elements in the same order. The
+    /// front of this 'deque' becomes the front of the 'queue'.
+    #[allow(dead_code)]
+    pub fn into_queue(self) -> Queue<T> {
+        Queue::from(Vec::from(self.deq))
+    }
+
+    /// Converts this 'deque' into a 'stack' that contains its elements in the same order. The
+    /// back of this 'deque' becomes the top of the 'stack'.
+    #[allow(dead_code)]
+    pub fn into_stack(self) -> Stack<T> {
+        Stack::from(Vec::from(self.deq))
+    }
+
+    /// Returns the element at the specified index from the front of this 'deque' without removing
+    /// it, or None if the index is out-of-bounds. Index 0 is the front element.
+    #[allow(dead_code)]
+    pub fn peek_at(&self, index: usize) -> Option<&T> {
+        self.deq.get(index)
+    }
+
+    /// Reverses the order of the elements in this 'deque', meaning the front element becomes the
+    /// back element.
+    #[allow(dead_code)]
+    pub fn reverse_in_place(&mut self) {
+        self.deq.make_contiguous().reverse();
+    }
+
+    /// Shrinks the capacity of this 'deque' as close to its length as possible, releasing unused
+    /// memory. Since a 'deque' is full when its length equals its capacity, it may be full
+    /// afterwards.
+    #[allow(dead_code)]
+    pub fn shrink_to_fit(&mut self) {
+        self.deq.shrink_to_fit();
+    }
+
     /// Creates a new 'deque' with the specified capacity.
     #[allow(dead_code)]
     pub fn with_capacity(capacity: usize) -> Self {
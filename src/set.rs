@@ -4,10 +4,12 @@
 //! of a set called 'Set'. This also contains implementations of the following: HashSet. A 'set' is
 //! an unordered group of elements that only contain unique elements.
 
-use core::fmt::{Debug, Formatter};
-use std::hash::Hash;
+use core::fmt::{Debug, Display, Formatter};
+#[cfg(feature = "std")]
+use core::hash::Hash;
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a 'set'.
 pub trait SetCollection<T>: Collection
@@ -42,6 +44,7 @@ pub trait SetCollection<T>: Collection
 /// of items, or an infinite number of items. Infinite 'sets' are created by marking a 'set' as
 /// a complement of its elements, meaning that the 'set' contains all elements except the elements
 /// listed in the 'set'.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Set<T>
     where
         T: PartialEq + Clone + Debug,
@@ -89,6 +92,43 @@ impl<T> Debug for Set<T>
     }
 }
 
+// Default function for Set
+impl<T> Default for Set<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Returns a new empty 'set'.
+    fn default() -> Self {
+        Set::new()
+    }
+}
+
+// Display function for Set
+impl<T> Display for Set<T>
+    where
+        T: Clone + PartialEq + Debug + Display,
+{
+    /// Displays this 'set' in a compact, human-readable form, such as {0, 1, 2}. A complement
+    /// 'set' is prefixed with '!'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        if self.not {
+            write!(f, "!")?;
+        }
+
+        write!(f, "{{")?;
+
+        for i in 0..self.arr.len() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", self.arr[i])?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
 // Empty function for Set
 impl<T> Empty for Set<T>
     where
@@ -99,6 +139,33 @@ impl<T> Empty for Set<T>
     fn is_empty(&self) -> bool { self.arr.is_empty() && !self.not }
 }
 
+// Extend function for Set
+impl<T> Extend<T> for Set<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Extends this 'set' with the elements of the specified iterator. Elements that are already
+    /// in this 'set' are not added again.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.add(i);
+        }
+    }
+}
+
+// FromIterator function for Set
+impl<T> FromIterator<T> for Set<T>
+    where
+        T: PartialEq + Clone + Debug,
+{
+    /// Creates a new 'set' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set: Set<T> = Set::new();
+        set.extend(iter);
+        set
+    }
+}
+
 // IntoIterator function for Set
 impl<T> IntoIterator for Set<T>
     where
@@ -107,7 +174,7 @@ impl<T> IntoIterator for Set<T>
     /// The Item type.
     type Item = T;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<T>;
+    type IntoIter = alloc::vec::IntoIter<T>;
 
     /// Converts this 'set' into an 'iterator'.
     fn into_iter(self) -> Self::IntoIter {
@@ -171,15 +238,16 @@ impl<T> Collection for Set<T>
         self.arr.capacity()
     }
 
-    /// Returns true if this 'set' contains the specified element.
+    /// Returns true if this 'set' contains the specified element. If this 'set' is a complement
+    /// of its contents, it contains every element that is not listed in its contents.
     fn contains(&self, item: &T) -> bool {
-        self.arr.contains(item)
+        self.arr.contains(item) != self.not
     }
 
     /// Returns true if this 'set' contains the specified vector.
     fn contains_all(&self, vec: &Vec<T>) -> bool {
         for i in 0..vec.len() {
-            if !self.arr.contains(&vec[i]) {
+            if !self.contains(&vec[i]) {
                 return false;
             }
         }
@@ -311,6 +379,23 @@ impl<T> Set<T>
         }
     }
 
+    /// Creates a new 'set' that contains the unique elements in the specified 'vector'. Returns
+    /// the new 'set' and the number of duplicate elements that were dropped.
+    #[allow(dead_code)]
+    pub fn from_vec_counting(v: &[T]) -> (Self, usize) {
+        let mut set: Set<T> = Set::new();
+        let mut dropped: usize = 0;
+
+        // If an element is already in the set, count it as dropped.
+        for item in v.iter() {
+            if !set.add(item.clone()) {
+                dropped += 1;
+            }
+        }
+
+        (set, dropped)
+    }
+
     /// Creates a new 'set' that contains all elements except the ones in the specified 'vector'.
     /// This is accomplished by marking the new 'set' as the complement of the specified 'vector'
     /// and having the new 'set' contain the items in the specified 'vector'. By definition, this
@@ -427,7 +512,7 @@ impl<T> Set<T>
                 set.add(i);
             }
             // If the new set is a complement and already contains the element from set b, remove it.
-            else if set.not && set.contains(&i) {
+            else if set.not && set.arr.contains(&i) {
                 set.remove(i);
             }
             // If the new set is not a complement, add elements from set b.
@@ -453,21 +538,21 @@ impl<T> Set<T>
             // If set a and b are not complements, and set b does not contain the element in set a,
             // add it to the new set.
             if !a.not && !b.not {
-                if !b.contains(&i) {
+                if !b.arr.contains(&i) {
                     set.add(i);
                 }
             }
             // If set a is a complement and set b is not, and set b does contain the element in set
             // a, add it to the new set.
             else if a.not && !b.not {
-                if b.contains(&i) {
+                if b.arr.contains(&i) {
                     set.add(i);
                 }
             }
             // If set a is not a complement and set b is, and set b contains the element in set a,
             // add it to the new set.
             else if !a.not && b.not {
-                if b.contains(&i) {
+                if b.arr.contains(&i) {
                     set.add(i);
                 }
             }
@@ -477,7 +562,7 @@ impl<T> Set<T>
         if a.not && !b.not {
             // If the set a does not contain the element in set b, add it to the new set.
             for i in b.clone().into_iter() {
-                if !a.contains(&i) {
+                if !a.arr.contains(&i) {
                     set.add(i);
                 }
             }
@@ -502,6 +587,17 @@ impl<T> Set<T>
         set
     }
 
+    /// Returns the number of elements in this 'set', or None if this 'set' is infinite, meaning
+    /// it is a complement of its contents.
+    #[allow(dead_code)]
+    pub fn cardinality(&self) -> Option<usize> {
+        if self.not {
+            return None;
+        }
+
+        Some(self.arr.len())
+    }
+
     /// Sets this 'set' to be a complement of itself, meaning if this 'set' was not a complement
     /// of its contents, it now contains everything except the elements listed in its contents.
     /// If this 'set' was a complement of its contents, it now contains only the elements listed
@@ -524,12 +620,120 @@ impl<T> Set<T>
     /// its contents.
     #[allow(dead_code)]
     pub fn is_infinite(&self) -> bool { self.not }
+
+    /// Returns an 'iterator' over the listed contents of this 'set'. If this 'set' is a
+    /// complement, these are the elements it does not contain. The 'set' is not changed.
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.arr.iter()
+    }
+
+    /// Changes this 'set' into the intersection of itself and the specified 'set', meaning it will
+    /// only contain the items that are in both 'sets'.
+    #[allow(dead_code)]
+    pub fn intersect(&mut self, other: &Set<T>) {
+        // If neither set is a complement, keep the elements also in the other set.
+        if !self.not && !other.not {
+            self.arr.retain(|i| other.arr.contains(i));
+        }
+        // If only the other set is a complement, keep the elements the other set does not exclude.
+        else if !self.not && other.not {
+            self.arr.retain(|i| !other.arr.contains(i));
+        }
+        // If only this set is a complement, keep the other set's elements this set does not
+        // exclude.
+        else if self.not && !other.not {
+            self.difference_from(other);
+            self.not = false;
+        }
+        // If both sets are complements, exclude the elements excluded by either set.
+        else {
+            self.add_all(other.arr.clone());
+        }
+    }
+
+    /// Changes this 'set' into the difference of itself and the specified 'set', meaning it will
+    /// only contain the items that are not also in the specified 'set'.
+    #[allow(dead_code)]
+    pub fn subtract(&mut self, other: &Set<T>) {
+        // If neither set is a complement, remove the elements in the other set.
+        if !self.not && !other.not {
+            self.arr.retain(|i| !other.arr.contains(i));
+        }
+        // If only the other set is a complement, keep the elements the other set excludes.
+        else if !self.not && other.not {
+            self.arr.retain(|i| other.arr.contains(i));
+        }
+        // If only this set is a complement, also exclude the elements in the other set.
+        else if self.not && !other.not {
+            self.add_all(other.arr.clone());
+        }
+        // If both sets are complements, keep the elements the other set excludes and this set does
+        // not.
+        else {
+            self.difference_from(other);
+            self.not = false;
+        }
+    }
+
+    /// Removes the specified element from the listed contents of this 'set', like 'remove'.
+    /// Returns the removed element, or None if it was not in the listed contents.
+    #[allow(dead_code)]
+    pub fn take(&mut self, item: &T) -> Option<T> {
+        for i in 0..self.arr.len() {
+            if self.arr[i] == *item {
+                return Some(self.arr.remove(i));
+            }
+        }
+
+        None
+    }
+
+    /// Changes this 'set' into the union of itself and the specified 'set', meaning it will
+    /// contain all items from both 'sets'.
+    #[allow(dead_code)]
+    pub fn unite(&mut self, other: &Set<T>) {
+        // If neither set is a complement, add the elements in the other set.
+        if !self.not && !other.not {
+            self.add_all(other.arr.clone());
+        }
+        // If only the other set is a complement, exclude what the other set excludes and this set
+        // does not contain.
+        else if !self.not && other.not {
+            self.difference_from(other);
+            self.not = true;
+        }
+        // If only this set is a complement, stop excluding the elements in the other set.
+        else if self.not && !other.not {
+            self.arr.retain(|i| !other.arr.contains(i));
+        }
+        // If both sets are complements, only exclude the elements excluded by both sets.
+        else {
+            self.arr.retain(|i| other.arr.contains(i));
+        }
+    }
+
+    /// Replaces the listed contents of this 'set' with the listed contents of the specified 'set'
+    /// that are not in the listed contents of this 'set'.
+    fn difference_from(&mut self, other: &Set<T>) {
+        let mut arr: Vec<T> = Vec::new();
+
+        for i in 0..other.arr.len() {
+            if !self.arr.contains(&other.arr[i]) {
+                arr.push(other.arr[i].clone());
+            }
+        }
+
+        self.arr = arr;
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 // HashSet
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A set of keys that are hashed for faster retrieval.
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -539,6 +743,7 @@ pub struct HashSet<T>
 }
 
 // Clear function for HashSet
+#[cfg(feature = "std")]
 impl<T> Clear for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -548,6 +753,7 @@ impl<T> Clear for HashSet<T>
 }
 
 // Clone function for HashSet
+#[cfg(feature = "std")]
 impl<T> Clone for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -557,6 +763,7 @@ impl<T> Clone for HashSet<T>
 }
 
 // Debug function for HashSet
+#[cfg(feature = "std")]
 impl<T> Debug for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -569,7 +776,43 @@ impl<T> Debug for HashSet<T>
     }
 }
 
+// Default function for HashSet
+#[cfg(feature = "std")]
+impl<T> Default for HashSet<T>
+    where
+        T: PartialEq + Clone + Debug + Eq + Hash,
+{
+    /// Returns a new empty 'hash set'.
+    fn default() -> Self {
+        HashSet::new()
+    }
+}
+
+// Display function for HashSet
+#[cfg(feature = "std")]
+impl<T> Display for HashSet<T>
+    where
+        T: PartialEq + Clone + Debug + Eq + Hash + Display,
+{
+    /// Displays this 'hash set' in a compact, human-readable form, such as {0, 1, 2}. The order
+    /// of the elements is not guaranteed.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{{")?;
+
+        for (i, item) in self.set.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", item)?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
 // Empty function for HashSet
+#[cfg(feature = "std")]
 impl<T> Empty for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -578,7 +821,37 @@ impl<T> Empty for HashSet<T>
     fn is_empty(&self) -> bool { self.set.is_empty() }
 }
 
+// Extend function for HashSet
+#[cfg(feature = "std")]
+impl<T> Extend<T> for HashSet<T>
+    where
+        T: PartialEq + Clone + Debug + Eq + Hash,
+{
+    /// Extends this 'hash set' with the elements of the specified iterator. Elements that are
+    /// already in this 'hash set' are not added again.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.set.insert(i);
+        }
+    }
+}
+
+// FromIterator function for HashSet
+#[cfg(feature = "std")]
+impl<T> FromIterator<T> for HashSet<T>
+    where
+        T: PartialEq + Clone + Debug + Eq + Hash,
+{
+    /// Creates a new 'hash set' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut hset: HashSet<T> = HashSet::new();
+        hset.extend(iter);
+        hset
+    }
+}
+
 // IntoIterator function for HashSet
+#[cfg(feature = "std")]
 impl<T> IntoIterator for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -593,6 +866,7 @@ impl<T> IntoIterator for HashSet<T>
 }
 
 // Length function for HashSet
+#[cfg(feature = "std")]
 impl<T> Len for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -602,6 +876,7 @@ impl<T> Len for HashSet<T>
 }
 
 // PartialEq function for HashSet
+#[cfg(feature = "std")]
 impl<T> PartialEq for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -613,6 +888,7 @@ impl<T> PartialEq for HashSet<T>
 }
 
 // Collection functions for HashSet
+#[cfg(feature = "std")]
 impl<T> Collection for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -650,6 +926,7 @@ impl<T> Collection for HashSet<T>
 }
 
 // SetCollection functions for HashSet
+#[cfg(feature = "std")]
 impl<T> SetCollection<T> for HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -722,6 +999,7 @@ impl<T> SetCollection<T> for HashSet<T>
 }
 
 // HashSet functions
+#[cfg(feature = "std")]
 impl<T> HashSet<T>
     where
         T: PartialEq + Clone + Debug + Eq + Hash,
@@ -741,4 +1019,35 @@ impl<T> HashSet<T>
 
         hset
     }
+
+    /// Creates a new 'hash set' that contains the unique elements in the specified 'vector'.
+    /// Returns the new 'hash set' and the number of duplicate elements that were dropped.
+    #[allow(dead_code)]
+    pub fn from_vec_counting(v: &[T]) -> (Self, usize) {
+        let mut hset: HashSet<T> = HashSet::new();
+        let mut dropped: usize = 0;
+
+        // If an element is already in the hash set, count it as dropped.
+        for item in v.iter() {
+            if !hset.set.insert(item.clone()) {
+                dropped += 1;
+            }
+        }
+
+        (hset, dropped)
+    }
+
+    /// Returns an 'iterator' over the elements of this 'hash set' in arbitrary order. The 'hash
+    /// set' is not changed.
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.set.iter()
+    }
+
+    /// Removes the specified element from this 'hash set'. Returns the removed element, or None
+    /// if it was not in this 'hash set'.
+    #[allow(dead_code)]
+    pub fn take(&mut self, item: &T) -> Option<T> {
+        self.set.take(item)
+    }
 }
\ No newline at end of file
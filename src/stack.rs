@@ -4,14 +4,44 @@
 //! of a stack called 'Stack'. A 'stack' is a list of elements that can only add or remove items
 //! from the top of the list.
 
-use core::fmt::{Debug, Formatter};
-use std::collections::VecDeque;
+pub mod eval;
+
+use core::fmt::{Debug, Display, Formatter};
+use alloc::collections::VecDeque;
 use len_trait::{Clear, Empty, Len};
 use crate::collection::*;
+use crate::prelude::*;
 
 /// The default capacity for a 'stack'.
 const DEF_STACK_CAPACITY: usize = 10;
 
+/// Contains the errors that can occur when building a 'stack' from its deserialized fields.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StackError {
+    /// The number of elements is greater than the capacity of the 'stack'.
+    CapacityExceeded {
+        /// The capacity the 'stack' was given.
+        capacity: usize,
+        /// The number of elements that were given.
+        actual: usize,
+    },
+}
+
+// Display function for StackError
+impl Display for StackError {
+    /// Displays a description of this 'stack error'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StackError::CapacityExceeded { capacity, actual } =>
+                write!(f, "expected at most {} elements but found {}", capacity, actual),
+        }
+    }
+}
+
+// Error function for StackError
+#[cfg(feature = "std")]
+impl std::error::Error for StackError {}
+
 // A trait for 'collections' that can implement a 'stack'.
 pub trait StackCollection<T>: Collection + Full
     where
@@ -32,12 +62,73 @@ pub trait StackCollection<T>: Collection + Full
 // Stack
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 /// A last-in-first-out collection that allows for adding/removing elements to/from the top.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "StackParts<T>", try_from = "StackParts<T>"))]
 pub struct Stack<T>
     where
         T: PartialEq + PartialOrd + Clone + Debug,
 {
-    /// The VecDeque backing this 'stack'.
-    deq: VecDeque<T>
+    /// The VecDeque backing this 'stack', with the bottom element at the front and the top
+    /// element at the back.
+    deq: VecDeque<T>,
+    /// Whether this 'stack' grows its capacity when pushing onto it while it is full.
+    growable: bool,
+}
+
+/// Contains the serialized fields of a 'stack', including its capacity, so the number of elements
+/// can be checked against the capacity when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize, serde::Serialize)]
+struct StackParts<T> {
+    /// The capacity of the 'stack'. A 'stack' without one is given room for more elements.
+    #[serde(default)]
+    capacity: Option<usize>,
+    /// The elements of the 'stack', from the bottom to the top.
+    deq: VecDeque<T>,
+    /// Whether the 'stack' is growable.
+    #[serde(default)]
+    growable: bool,
+}
+
+// From function for StackParts
+#[cfg(feature = "serde")]
+impl<T> From<Stack<T>> for StackParts<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates the serialized fields of the specified 'stack'.
+    fn from(stack: Stack<T>) -> Self {
+        StackParts {
+            capacity: Some(stack.deq.capacity()),
+            deq: stack.deq,
+            growable: stack.growable,
+        }
+    }
+}
+
+// TryFrom function for Stack
+#[cfg(feature = "serde")]
+impl<T> TryFrom<StackParts<T>> for Stack<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Error type.
+    type Error = StackError;
+
+    /// Creates a 'stack' from its deserialized fields. Returns a 'stack error' if there are more
+    /// elements than the capacity allows.
+    fn try_from(parts: StackParts<T>) -> Result<Self, Self::Error> {
+        let capacity: usize = parts.capacity.unwrap_or(parts.deq.len() + DEF_STACK_CAPACITY);
+
+        // If there are more elements than the capacity allows, return an error.
+        if parts.deq.len() > capacity {
+            return Err(StackError::CapacityExceeded { capacity, actual: parts.deq.len() });
+        }
+
+        let mut deq: VecDeque<T> = VecDeque::with_capacity(capacity);
+        deq.extend(parts.deq);
+        Ok(Stack { deq, growable: parts.growable })
+    }
 }
 
 // Clear function for Stack
@@ -58,7 +149,7 @@ impl<T> Clone for Stack<T>
 {
     /// Returns a clone of this 'stack'.
     fn clone(&self) -> Self {
-        Stack { deq: self.deq.clone() }
+        Stack { deq: self.deq.clone(), growable: self.growable }
     }
 }
 
@@ -71,10 +162,22 @@ impl<T> Debug for Stack<T>
     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Stack")
             .field("deq", &self.deq)
+            .field("growable", &self.growable)
             .finish()
     }
 }
 
+// Default function for Stack
+impl<T> Default for Stack<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Returns a new empty 'stack' with a default capacity of 10.
+    fn default() -> Self {
+        Stack::new()
+    }
+}
+
 // Empty function for Stack
 impl<T> Empty for Stack<T>
     where
@@ -86,6 +189,60 @@ impl<T> Empty for Stack<T>
     }
 }
 
+// Extend function for Stack
+impl<T> Extend<T> for Stack<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Extends this 'stack' with the elements of the specified iterator. The elements are added
+    /// in order and are not limited by this 'stack's' capacity.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for i in iter {
+            self.deq.push_back(i);
+        }
+    }
+}
+
+// From function for Stack
+impl<T> From<Vec<T>> for Stack<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'stack' that contains the elements of the specified vector. The last element
+    /// of the vector becomes the top of the 'stack'. The 'stack' is given room for more
+    /// elements, so it does not start out full.
+    fn from(vec: Vec<T>) -> Self {
+        let mut deq: VecDeque<T> = VecDeque::with_capacity(vec.len() + DEF_STACK_CAPACITY);
+        deq.extend(vec);
+        Stack { deq, growable: false }
+    }
+}
+
+// From function for Vec
+impl<T> From<Stack<T>> for Vec<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new vector that contains the elements of the specified 'stack'. The elements are
+    /// ordered from the bottom of the 'stack' to the top.
+    fn from(stack: Stack<T>) -> Self {
+        Vec::from(stack.deq)
+    }
+}
+
+// FromIterator function for Stack
+impl<T> FromIterator<T> for Stack<T>
+    where
+        T: PartialEq + PartialOrd + Clone + Debug,
+{
+    /// Creates a new 'stack' that contains the elements of the specified iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut stack: Stack<T> = Stack { deq: VecDeque::new(), growable: false };
+        stack.extend(iter);
+        stack
+    }
+}
+
 // Full function for Stack
 impl<T> Full for Stack<T>
     where
@@ -107,7 +264,8 @@ impl<T> IntoIterator for Stack<T>
     /// The IntoIter type.
     type IntoIter = alloc::collections::vec_deque::IntoIter<T>;
 
-    /// Converts this 'stack' into an 'iterator'.
+    /// Converts this 'stack' into an 'iterator'. The elements are yielded from the bottom of the
+    /// 'stack' to the top, meaning in the order they were pushed. Use 'iter' to go from the top.
     fn into_iter(self) -> Self::IntoIter {
         self.deq.into_iter()
     }
@@ -177,7 +335,7 @@ impl<T> Collection for Stack<T>
         true
     }
 
-    /// Returns a 'vector' containing the elements of this 'stack'.
+    /// Returns a 'vector' containing the elements of this 'stack', from the bottom to the top.
     fn to_vec(&self) -> Vec<T> {
         let mut vec: Vec<T> = Vec::new();
 
@@ -197,12 +355,14 @@ impl<T> StackCollection<T> for Stack<T>
     /// Removes the top element from the 'stack' if there is one. Returns the top element or
     /// None if there isn't one.
     fn pop(&mut self) -> Option<T> {
-        self.deq.pop_front()
+        self.deq.pop_back()
     }
 
-    /// Pushes the specified element onto the top of the 'stack'. Returns true if successful.
+    /// Pushes the specified element onto the top of the 'stack'. Returns true if successful or
+    /// false if the 'stack' is full and not growable. A growable 'stack' increases its capacity
+    /// instead of failing.
     fn push(&mut self, item: T) -> bool {
-        if self.is_full() { return false; }
+        if self.is_full() && !self.growable { return false; }
 
         self.deq.push_back(item);
 
@@ -210,7 +370,7 @@ impl<T> StackCollection<T> for Stack<T>
     }
 
     /// Returns the top element in the 'stack' or None if there isn't one.
-    fn peek_top(&self) -> Option<&T> { self.deq.front() }
+    fn peek_top(&self) -> Option<&T> { self.deq.back() }
 }
 
 // Stack functions
@@ -220,13 +380,13 @@ impl<T> Stack<T>
 {
     /// Creates a new empty 'stack' with a default capacity of 10.
     pub fn new() -> Self {
-        Stack { deq: VecDeque::with_capacity(DEF_STACK_CAPACITY) }
+        Stack { deq: VecDeque::with_capacity(DEF_STACK_CAPACITY), growable: false }
     }
 
     /// Creates a new 'stack' that contains the elements in the specified 'vector'.
     #[allow(dead_code)]
     pub fn from_vec(v: &Vec<T>) -> Self {
-        let mut stack: Stack<T> = Stack { deq: VecDeque::new() };
+        let mut stack: Stack<T> = Stack { deq: VecDeque::new(), growable: false };
 
         for i in v.into_iter() {
             stack.deq.push_back(i.clone());
@@ -235,9 +395,39 @@ impl<T> Stack<T>
         stack
     }
 
+    /// Removes all elements from this 'stack' and returns them in a vector from the top to the
+    /// bottom, meaning in the order they would be popped. The capacity of this 'stack' is kept.
+    #[allow(dead_code)]
+    pub fn drain(&mut self) -> Vec<T> {
+        self.deq.drain(..).rev().collect()
+    }
+
+    /// Returns an 'iterator' over the elements of this 'stack' from the top to the bottom,
+    /// meaning in the order they would be popped. The 'stack' is not changed.
+    #[allow(dead_code)]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.deq.iter().rev()
+    }
+
+    /// Sets whether this 'stack' is growable. A growable 'stack' increases its capacity when an
+    /// element is pushed onto it while it is full, instead of the push failing. A 'stack' is not
+    /// growable by default.
+    #[allow(dead_code)]
+    pub fn set_growable(&mut self, growable: bool) {
+        self.growable = growable;
+    }
+
+    /// Shrinks the capacity of this 'stack' as close to its length as possible, releasing unused
+    /// memory. Since a 'stack' is full when its length equals its capacity, it may be full
+    /// afterwards.
+    #[allow(dead_code)]
+    pub fn shrink_to_fit(&mut self) {
+        self.deq.shrink_to_fit();
+    }
+
     /// Creates a new 'stack' with the specified capacity.
     #[allow(dead_code)]
     pub fn with_capacity(capacity: usize) -> Self {
-        Stack { deq: VecDeque::with_capacity(capacity) }
+        Stack { deq: VecDeque::with_capacity(capacity), growable: false }
     }
 }
\ No newline at end of file
@@ -0,0 +1,92 @@
+//! # Eval
+//!
+//! Contains an 'eval_rpn' function for evaluating an expression written in reverse Polish notation
+//! using a 'stack', as well as an 'EvalError' enum for the errors that can occur while evaluating.
+//! In reverse Polish notation, each operator follows its operands, such as 3 4 + for 3 + 4.
+
+use core::fmt::{Display, Formatter};
+use crate::stack::{Stack, StackCollection};
+
+/// Contains the errors that can occur when evaluating an expression. Each error holds the index of
+/// the token where evaluation failed, if there is one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EvalError {
+    /// An operator divides by zero.
+    DivisionByZero(usize),
+    /// The expression contains no tokens.
+    EmptyExpression,
+    /// A token is neither a number nor a supported operator.
+    InvalidToken(usize),
+    /// An operator does not have two operands to apply to.
+    MissingOperand(usize),
+    /// The expression leaves more than one value once every token is evaluated.
+    TooManyOperands,
+}
+
+// Display function for EvalError
+impl Display for EvalError {
+    /// Displays a description of this 'eval error'.
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            EvalError::DivisionByZero(i) => write!(f, "division by zero at token {}", i),
+            EvalError::EmptyExpression => write!(f, "empty expression"),
+            EvalError::InvalidToken(i) => write!(f, "invalid token at index {}", i),
+            EvalError::MissingOperand(i) => write!(f, "missing operand at token {}", i),
+            EvalError::TooManyOperands => write!(f, "too many operands"),
+        }
+    }
+}
+
+// Error function for EvalError
+#[cfg(feature = "std")]
+impl std::error::Error for EvalError {}
+
+/// Evaluates the specified reverse Polish notation expression, where each token is either a number
+/// or one of the operators +, -, * and /. Returns the result, or an 'eval error' if a token is
+/// invalid, an operator is missing an operand, an operator divides by zero, or the expression does
+/// not reduce to a single value.
+#[allow(dead_code)]
+pub fn eval_rpn(tokens: &[&str]) -> Result<f64, EvalError> {
+    let mut stack: Stack<f64> = Stack::new();
+
+    // Allow the stack to grow past its default capacity for long expressions.
+    stack.set_growable(true);
+
+    for (i, &token) in tokens.iter().enumerate() {
+        // If the token is an operator, apply it to the top two values.
+        if token == "+" || token == "-" || token == "*" || token == "/" {
+            let b: f64 = stack.pop().ok_or(EvalError::MissingOperand(i))?;
+            let a: f64 = stack.pop().ok_or(EvalError::MissingOperand(i))?;
+
+            let result: f64 = match token {
+                "+" => a + b,
+                "-" => a - b,
+                "*" => a * b,
+                _ => {
+                    // If the divisor is zero, return an error.
+                    if b == 0.0 {
+                        return Err(EvalError::DivisionByZero(i));
+                    }
+
+                    a / b
+                },
+            };
+
+            stack.push(result);
+        }
+        // If the token is a number, push it onto the stack.
+        else {
+            match token.parse::<f64>() {
+                Ok(n) => { stack.push(n); },
+                Err(_) => return Err(EvalError::InvalidToken(i)),
+            }
+        }
+    }
+
+    // The expression must reduce to exactly one value.
+    match stack.pop() {
+        Some(result) if stack.peek_top().is_none() => Ok(result),
+        Some(_) => Err(EvalError::TooManyOperands),
+        None => Err(EvalError::EmptyExpression),
+    }
+}
@@ -7,12 +7,14 @@
 //! collection of 'linked lists', etc.).
 
 use core::fmt::{Debug, Formatter};
-use std::ops::{Index, IndexMut};
+use core::ops::{Index, IndexMut};
 use len_trait::{Clear, Empty, Len};
 use crate::collection::Collection;
 use crate::map::traversable::linked::LinkedList;
-use crate::array::list::List;
+use crate::array::list::{List, ListCollection};
 use crate::map::KeyValue;
+use crate::grid::AdjacencyMatrix;
+use crate::prelude::*;
 
 // A trait for 'collections' that can implement a super type.
 pub trait SuperCollection<T>: Collection + Index<usize> + IndexMut<usize>
@@ -27,6 +29,10 @@ pub trait SuperCollection<T>: Collection + Index<usize> + IndexMut<usize>
     /// Returns the 'collection' at the specified index, or None if the index is out-of-bounds.
     fn get(&self, index: usize) -> Option<&Self::CType>;
 
+    /// Returns a mutable reference to the 'collection' at the specified index, or None if the
+    /// index is out-of-bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::CType>;
+
     /// Returns the index of the specified 'collection', if it's in this 'super list',
     /// otherwise returns None.
     fn index_of(&self, c: &Self::CType) -> Option<usize>;
@@ -149,7 +155,7 @@ impl<T> IntoIterator for AdjacencyList<T>
     /// Item type.
     type Item = LinkedList<T>;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<LinkedList<T>>;
+    type IntoIter = alloc::vec::IntoIter<LinkedList<T>>;
 
     /// Converts this 'adjacency list' into an 'iterator'. This returns an iterator over each
     /// 'linked list' in this 'adjacency list'. This iterator does not iterate over each
@@ -262,6 +268,16 @@ impl<T> SuperCollection<T> for AdjacencyList<T>
         Some(&self.arr[index])
     }
 
+    /// Returns a mutable reference to the 'linked list' at the specified index, or None if the
+    /// index is out-of-bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::CType> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(&mut self.arr[index])
+    }
+
     /// Returns the index of the specified 'linked list', if it's in this 'adjacency list',
     /// otherwise returns None.
     fn index_of(&self, c: &Self::CType) -> Option<usize> {
@@ -338,6 +354,66 @@ impl<T> AdjacencyList<T>
     /// Creates a new empty 'adjacency list'.
     #[allow(dead_code)]
     pub fn new() -> Self { AdjacencyList { arr: Vec::new() } }
+
+    /// Returns the number of 'edges' in this 'adjacency list', which is the sum of the lengths
+    /// of every 'linked list' in this 'adjacency list'.
+    #[allow(dead_code)]
+    pub fn edge_count(&self) -> usize {
+        let mut edges: usize = 0;
+
+        for i in 0..self.arr.len() {
+            edges += self.arr[i].len();
+        }
+
+        edges
+    }
+
+    /// Returns a 'vector' containing the adjacency entries of the 'node' at the specified index,
+    /// or None if the index is out-of-bounds.
+    #[allow(dead_code)]
+    pub fn neighbors(&self, node: usize) -> Option<Vec<T>> {
+        // If node is out-of-bounds, return None.
+        if node >= self.arr.len() {
+            return None;
+        }
+
+        let mut vec: Vec<T> = Vec::new();
+
+        // Add each entry in the node's linked list to the vector.
+        for i in 0..self.arr[node].len() {
+            vec.push(self.arr[node][i].clone());
+        }
+
+        Some(vec)
+    }
+}
+
+// AdjacencyList functions for node indices
+impl AdjacencyList<usize> {
+    /// Returns an 'adjacency matrix' representing the same 'edges' as this 'adjacency list'. The
+    /// 'adjacency matrix' element at (i, j) is set to 1.0 if j appears in the 'linked list' at
+    /// index i, otherwise it is 0.0.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if an entry in this 'adjacency list' is not a valid 'node' index.
+    #[allow(dead_code)]
+    pub fn to_matrix(&self) -> AdjacencyMatrix {
+        let mut amtx: AdjacencyMatrix = AdjacencyMatrix::new_size(self.arr.len(), self.arr.len());
+
+        // Set the matrix element for each entry in each node's linked list.
+        for i in 0..self.arr.len() {
+            for j in 0..self.arr[i].len() {
+                if self.arr[i][j] >= self.arr.len() {
+                    panic!("Cannot convert adjacency list due to out-of-bounds node index.");
+                }
+
+                amtx[(i, self.arr[i][j])] = 1.0;
+            }
+        }
+
+        amtx
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -429,7 +505,7 @@ impl<T> IntoIterator for SuperList<T>
     /// Item type.
     type Item = List<T>;
     /// The IntoIter type.
-    type IntoIter = std::vec::IntoIter<List<T>>;
+    type IntoIter = alloc::vec::IntoIter<List<T>>;
 
     /// Converts this 'super list' into an 'iterator'. This returns an iterator over each 'list'
     /// in this 'super list'. This iterator does not iterate over each element in each 'list'.
@@ -541,6 +617,16 @@ impl<T> SuperCollection<T> for SuperList<T>
         Some(&self.arr[index])
     }
 
+    /// Returns a mutable reference to the 'list' at the specified index, or None if the index is
+    /// out-of-bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut Self::CType> {
+        if index >= self.len() {
+            return None;
+        }
+
+        Some(&mut self.arr[index])
+    }
+
     /// Returns the index of the specified 'list', if it's in this 'super list', otherwise
     /// returns None.
     fn index_of(&self, c: &Self::CType) -> Option<usize> {
@@ -617,4 +703,27 @@ impl<T> SuperList<T>
     /// Creates a new empty 'super list'.
     #[allow(dead_code)]
     pub fn new() -> Self { SuperList { arr: Vec::new() } }
+
+    /// Returns a single 'list' containing the elements of every 'list' in this 'super list',
+    /// in order.
+    #[allow(dead_code)]
+    pub fn flatten(&self) -> List<T> {
+        let mut list: List<T> = List::new();
+
+        // Append each inner list's elements to the flattened list.
+        for i in 0..self.arr.len() {
+            list.append_all(self.arr[i].to_vec());
+        }
+
+        list
+    }
+
+    /// Replaces each 'list' in this 'super list' with the result of applying the specified
+    /// function to it.
+    #[allow(dead_code)]
+    pub fn map_each<F: Fn(&List<T>) -> List<T>>(&mut self, f: F) {
+        for i in 0..self.arr.len() {
+            self.arr[i] = f(&self.arr[i]);
+        }
+    }
 }
\ No newline at end of file